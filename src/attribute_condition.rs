@@ -0,0 +1,126 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyAttributeError};
+use pyo3::types::{PyDict, PyString, PyType};
+
+use crate::check_type_condition::{find_mismatch, TypeOptions};
+use crate::helper;
+
+/// Check that an object has the given attributes.
+///
+/// The expected type of every attribute is checked in the same way as in
+/// check_type, so lists of types, None and typing constructs can be used.
+///
+/// Args:
+///     obj (Any): object to check
+///     attributes (dict, str or Iterable[str]): name(s) of the required
+///         attributes, or a dict mapping them to their expected types. Use the builtin
+///         callable to require a callable attribute, and None or typing.Any
+///         to accept any value, e.g.
+///         {"read": callable, "name": str, "size": int}
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing all missing and mistyped
+///         attributes.
+///     strict (bool): require exact types, as in check_type
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     AttributeError by default. The exception has a `missing` and a
+///     `mistyped` attribute, lists of the names of the offending attributes.
+///
+/// >>> check_has_attributes(io.StringIO(), {"read": callable, "name": str})
+/// Traceback (most recent call last):
+///     ...
+/// AttributeError: missing attributes: name
+#[pyfunction]
+#[pyo3(signature = (obj, attributes, /, handle_with=None, message=None, *, strict=false))]
+pub fn check_has_attributes(_py: Python,
+                            obj: &PyAny,
+                            attributes: &PyAny,
+                            handle_with: Option<&PyType>,
+                            message: Option<&str>,
+                            strict: bool) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let expected: Vec<(String, Option<&PyAny>)> = match attributes.downcast::<PyDict>() {
+        Ok(dict) => {
+            dict.iter()
+                .map(|(name, expected_type)| Ok((name.extract()?, (!expected_type.is_none()).then_some(expected_type))))
+                .collect::<PyResult<_>>()?
+        }
+        // a single name, not an iterable of its characters
+        Err(_) if attributes.is_instance_of::<PyString>() => {
+            vec![(attributes.extract()?, None)]
+        }
+        Err(_) => {
+            attributes.iter()?.map(|name| Ok((name?.extract()?, None))).collect::<PyResult<_>>()?
+        }
+    };
+
+    let callable = _py.import("builtins")?.getattr("callable")?;
+    let options = TypeOptions { strict, ..TypeOptions::default() };
+    let mut missing: Vec<String> = Vec::new();
+    let mut mistyped: Vec<String> = Vec::new();
+    let mut details: Vec<String> = Vec::new();
+
+    for (name, expected_type) in expected {
+        let value = match obj.getattr(name.as_str()) {
+            Ok(value) => { value }
+            Err(_) => {
+                missing.push(name);
+                continue;
+            }
+        };
+        let problem = match expected_type {
+            None => { None }
+            Some(expected_type) if expected_type.is(callable) => {
+                (!value.is_callable()).then(|| format!("{}: expected callable, got {}", name, value.get_type().name().unwrap_or("")))
+            }
+            Some(expected_type) => {
+                find_mismatch(_py, value, expected_type, &name, &options)?.map(|found| found.to_string())
+            }
+        };
+        if let Some(problem) = problem {
+            details.push(problem);
+            mistyped.push(name);
+        }
+    }
+
+    if missing.is_empty() && mistyped.is_empty() {
+        return Ok(());
+    }
+
+    if !missing.is_empty() {
+        details.insert(0, format!("missing attributes: {}", missing.join(", ")));
+    }
+    let default_message = details.join("; ");
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyAttributeError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("missing", missing.into_py(_py)), ("mistyped", mistyped.into_py(_py))]))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (obj, attributes, /, handle_with=None, message=None, *, strict=false))]
+pub fn assert_has_attributes(_py: Python,
+                             obj: &PyAny,
+                             attributes: &PyAny,
+                             handle_with: Option<&PyType>,
+                             message: Option<&str>,
+                             strict: bool) -> PyResult<()> {
+    check_has_attributes(_py, obj, attributes, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, strict)
+}
@@ -0,0 +1,119 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyBaseException};
+use pyo3::types::{PyDict, PyString, PyTuple, PyType};
+
+use crate::helper;
+
+pyo3::create_exception!(reasycheck, SignatureError, PyBaseException);
+
+fn raise_signature_error(_py: Python, handle_with: Option<&PyType>, message: Option<&str>) -> Result<(), PyErr> {
+    match handle_with {
+        None => { Err(helper::check_handle_warning_with(Some(_py.get_type::<SignatureError>()), message)) }
+        Some(_) => { Err(helper::check_handle_warning_with(handle_with, message)) }
+    }
+}
+
+/// Check that an object is callable with the given arguments.
+///
+/// The signature of the callable (see inspect.signature) must be able to
+/// bind `positional` positional arguments together with the keyword
+/// arguments named in `keywords`. Callables whose signature cannot be
+/// inspected, e.g. some builtins, are only checked for being callable.
+///
+/// Args:
+///     func (Callable): object to check
+///     positional (int): number of positional arguments func will be called
+///         with
+///     keywords (str or Iterable[str]): name(s) of the keyword arguments
+///         func will be called with
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means describing the mismatch.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     SignatureError by default.
+///
+/// >>> def callback(event, *, retries): ...
+/// >>> check_callable(callback, 2)
+/// Traceback (most recent call last):
+///     ...
+/// SignatureError: callback cannot be called with 2 positional argument(s): too many positional arguments
+#[pyfunction]
+#[pyo3(signature = (func, /, positional=None, keywords=None, handle_with=None, message=None))]
+pub fn check_callable(_py: Python,
+                      func: &PyAny,
+                      positional: Option<usize>,
+                      keywords: Option<&PyAny>,
+                      handle_with: Option<&PyType>,
+                      message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    if !func.is_callable() {
+        let default_message = format!("'{}' object is not callable", func.get_type().name()?);
+        return raise_signature_error(_py, handle_with, Some(message.unwrap_or(&default_message)));
+    }
+
+    if positional.is_none() && keywords.is_none() {
+        return Ok(());
+    }
+
+    let signature = match _py.import("inspect")?.call_method1("signature", (func,)) {
+        Ok(signature) => { signature }
+        Err(_) => { return Ok(()) }
+    };
+
+    let positional = positional.unwrap_or(0);
+    let args = PyTuple::new(_py, vec![_py.None(); positional]);
+    let kwargs = PyDict::new(_py);
+    let mut names: Vec<String> = Vec::new();
+    if let Some(keywords) = keywords {
+        if keywords.is_instance_of::<PyString>() {
+            // a single name, not an iterable of its characters
+            names.push(keywords.extract()?);
+        } else {
+            for name in keywords.iter()? {
+                names.push(name?.extract()?);
+            }
+        }
+        for name in &names {
+            kwargs.set_item(name, _py.None())?;
+        }
+    }
+
+    match signature.call_method("bind", args, Some(kwargs)) {
+        Ok(_) => { Ok(()) }
+        Err(err) => {
+            let func_name = match func.getattr("__qualname__") {
+                Ok(name) => { name.to_string() }
+                Err(_) => { func.repr()?.to_string() }
+            };
+            let mut arguments = format!("{} positional argument(s)", positional);
+            if !names.is_empty() {
+                arguments.push_str(&format!(" and keyword argument(s) {}", names.join(", ")));
+            }
+            let default_message = format!("{} cannot be called with {}: {}", func_name, arguments, err.value(_py));
+            raise_signature_error(_py, handle_with, Some(message.unwrap_or(&default_message)))
+        }
+    }
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (func, /, positional=None, keywords=None, handle_with=None, message=None))]
+pub fn assert_callable(_py: Python,
+                       func: &PyAny,
+                       positional: Option<usize>,
+                       keywords: Option<&PyAny>,
+                       handle_with: Option<&PyType>,
+                       message: Option<&str>) -> PyResult<()> {
+    check_callable(_py, func, positional, keywords, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
@@ -0,0 +1,253 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyTypeError};
+use pyo3::types::{PyDict, PyList, PyTuple, PyType};
+
+use crate::helper;
+
+/// Attributes which typing.Protocol and the class machinery add to every
+/// class, so they are not members a protocol requires.
+const SPECIAL_ATTRIBUTES: [&str; 27] = [
+    "__abstractmethods__", "__annotations__", "__dict__", "__doc__", "__init__", "__module__",
+    "__new__", "__slots__", "__subclasshook__", "__weakref__", "__class_getitem__",
+    "__parameters__", "__orig_bases__", "__orig_class__", "__qualname__", "__init_subclass__",
+    "__match_args__", "__type_params__", "__firstlineno__", "__static_attributes__",
+    "__protocol_attrs__", "__non_callable_proto_members__", "__annotate__", "__annotate_func__",
+    "__annotations_cache__", "_is_protocol", "_is_runtime_protocol",
+];
+
+/// Names of the members (methods and annotated attributes) declared by a
+/// protocol class and its protocol bases.
+fn protocol_members(_py: Python, protocol: &PyType) -> PyResult<Vec<String>> {
+    let typing = _py.import("typing")?;
+    let mut members: Vec<String> = Vec::new();
+
+    for base in protocol.getattr("__mro__")?.iter()? {
+        let base = base?;
+        if base.is(_py.get_type::<PyAny>()) || base.is(typing.getattr("Generic")?) || base.is(typing.getattr("Protocol")?) {
+            continue;
+        }
+        let mut names: Vec<String> = base.getattr("__dict__")?.iter()?.map(|name| name?.extract()).collect::<PyResult<_>>()?;
+        if let Ok(annotations) = base.getattr("__annotations__") {
+            names.extend(annotations.iter()?.map(|name| name?.extract()).collect::<PyResult<Vec<String>>>()?);
+        }
+        for name in names {
+            if !name.starts_with("_abc_") && !SPECIAL_ATTRIBUTES.contains(&name.as_str()) && !members.contains(&name) {
+                members.push(name);
+            }
+        }
+    }
+    Ok(members)
+}
+
+/// Whether `cls` declares an attribute only through an annotation, e.g. one
+/// that is assigned in __init__.
+fn has_annotation(cls: &PyType, name: &str) -> PyResult<bool> {
+    for base in cls.getattr("__mro__")?.iter()? {
+        if let Ok(annotations) = base?.getattr("__annotations__") {
+            if annotations.downcast::<PyDict>().is_ok_and(|annotations| annotations.contains(name).unwrap_or(false)) {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// The number of required and the maximum number of positional parameters
+/// of a callable; the maximum is None for callables accepting *args.
+fn positional_arity(_py: Python, function: &PyAny) -> PyResult<Option<(usize, Option<usize>)>> {
+    let inspect = _py.import("inspect")?;
+    let signature = match inspect.call_method1("signature", (function,)) {
+        Ok(signature) => { signature }
+        // builtins may not expose their signature
+        Err(_) => { return Ok(None) }
+    };
+    let empty = inspect.getattr("Parameter")?.getattr("empty")?;
+    let mut required = 0;
+    let mut maximum = Some(0);
+
+    for parameter in signature.getattr("parameters")?.call_method0("values")?.iter()? {
+        let parameter = parameter?;
+        match parameter.getattr("kind")?.extract::<u8>()? {
+            // POSITIONAL_ONLY and POSITIONAL_OR_KEYWORD
+            0 | 1 => {
+                maximum = maximum.map(|maximum| maximum + 1);
+                if parameter.getattr("default")?.is(empty) {
+                    required += 1;
+                }
+            }
+            // VAR_POSITIONAL
+            2 => { maximum = None }
+            _ => {}
+        }
+    }
+    Ok(Some((required, maximum)))
+}
+
+/// The positional arity of the member `name` of `owner` as seen by a caller
+/// on an instance, i.e. without `self` for instance methods. Static and
+/// class methods are looked up through the descriptor, so they take no
+/// `self`.
+fn member_arity(_py: Python, owner: &PyType, name: &str, member: &PyAny) -> PyResult<Option<(usize, Option<usize>)>> {
+    let types = _py.import("types")?;
+    let takes_self = match _py.import("inspect")?.call_method1("getattr_static", (owner, name)) {
+        Ok(definition) => {
+            definition.is_instance(types.getattr("FunctionType")?)?
+                || definition.is_instance(types.getattr("MethodDescriptorType")?)?
+                || definition.is_instance(types.getattr("WrapperDescriptorType")?)?
+        }
+        Err(_) => { false }
+    };
+    let offset = usize::from(takes_self);
+
+    Ok(positional_arity(_py, member)?.map(|(required, maximum)| {
+        (required.saturating_sub(offset), maximum.map(|maximum| maximum.saturating_sub(offset)))
+    }))
+}
+
+/// Check a class: that it subclasses a base, implements all abstract methods
+/// and structurally satisfies a protocol.
+///
+/// Args:
+///     cls (type): class to check
+///     subclass_of (type or tuple of types): class(es) cls must be a
+///         subclass of (at least one of them)
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means describing what is wrong, e.g. which
+///         members are missing.
+///     protocol (type): a class, usually a typing.Protocol, whose methods
+///         and annotated attributes cls must provide. Methods must be able to
+///         take the same number of positional arguments as in the protocol.
+///     concrete (bool): require that cls implements all abstract methods,
+///         so that it can be instantiated
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     TypeError by default. The exception has a `missing` attribute, a list
+///     of the missing members (abstract methods or protocol members).
+///
+/// >>> class Reader(Protocol):
+/// ...     def read(self, size): ...
+/// >>> class Broken:
+/// ...     def read(self): ...
+/// >>> check_class(Broken, protocol=Reader)
+/// Traceback (most recent call last):
+///     ...
+/// TypeError: Broken does not satisfy Reader: read accepts 0 positional argument(s) instead of 1
+#[pyfunction]
+#[pyo3(signature = (cls, /, subclass_of=None, handle_with=None, message=None, *, protocol=None, concrete=false))]
+pub fn check_class(_py: Python,
+                   cls: &PyAny,
+                   subclass_of: Option<&PyAny>,
+                   handle_with: Option<&PyType>,
+                   message: Option<&str>,
+                   protocol: Option<&PyType>,
+                   concrete: bool) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let cls: &PyType = match cls.downcast() {
+        Ok(cls) => { cls }
+        Err(_) => { return Err(PyTypeError::new_err(format!("expected a class, got '{}' object", cls.get_type().name()?))) }
+    };
+    let cls_name = cls.name()?;
+    let mut problems: Vec<String> = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+
+    if let Some(base) = subclass_of {
+        let base = if base.is_instance_of::<PyList>() { PyTuple::new(_py, base.downcast::<PyList>()?).into() } else { base };
+        if !cls.is_subclass(base)? {
+            problems.push(format!("{} is not a subclass of {}", cls_name, crate::check_type_condition::type_name(base)));
+        }
+    }
+
+    if concrete {
+        if let Ok(abstract_methods) = cls.getattr("__abstractmethods__") {
+            let mut names: Vec<String> = abstract_methods.iter()?.map(|name| name?.extract()).collect::<PyResult<_>>()?;
+            if !names.is_empty() {
+                names.sort();
+                problems.push(format!("{} does not implement abstract methods: {}", cls_name, names.join(", ")));
+                missing.extend(names);
+            }
+        }
+    }
+
+    if let Some(protocol) = protocol {
+        let mut protocol_problems = Vec::new();
+        let mut protocol_missing = Vec::new();
+
+        for name in protocol_members(_py, protocol)? {
+            let expected = protocol.getattr(name.as_str()).ok();
+            let actual = match cls.getattr(name.as_str()) {
+                Ok(actual) => { actual }
+                Err(_) => {
+                    if expected.is_some() || !has_annotation(cls, &name)? {
+                        protocol_missing.push(name);
+                    }
+                    continue;
+                }
+            };
+            let expected = match expected {
+                Some(expected) if expected.is_callable() => { expected }
+                _ => { continue }
+            };
+            if !actual.is_callable() {
+                protocol_problems.push(format!("{} is not callable", name));
+                continue;
+            }
+            if let (Some((_, Some(expected_count))), Some((required, maximum))) = (member_arity(_py, protocol, &name, expected)?, member_arity(_py, cls, &name, actual)?) {
+                if required > expected_count || maximum.is_some_and(|maximum| maximum < expected_count) {
+                    let accepted = match maximum {
+                        Some(maximum) if maximum == required => { format!("{}", maximum) }
+                        Some(maximum) => { format!("{} to {}", required, maximum) }
+                        None => { format!("at least {}", required) }
+                    };
+                    protocol_problems.push(format!("{} accepts {} positional argument(s) instead of {}", name, accepted, expected_count));
+                }
+            }
+        }
+
+        if !protocol_missing.is_empty() {
+            protocol_problems.insert(0, format!("missing {}", protocol_missing.join(", ")));
+        }
+        if !protocol_problems.is_empty() {
+            problems.push(format!("{} does not satisfy {}: {}", cls_name, protocol.name()?, protocol_problems.join("; ")));
+        }
+        missing.extend(protocol_missing);
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    let default_message = problems.join("; ");
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyTypeError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("missing", missing.into_py(_py))]))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (cls, /, subclass_of=None, handle_with=None, message=None, *, protocol=None, concrete=false))]
+pub fn assert_class(_py: Python,
+                    cls: &PyAny,
+                    subclass_of: Option<&PyAny>,
+                    handle_with: Option<&PyType>,
+                    message: Option<&str>,
+                    protocol: Option<&PyType>,
+                    concrete: bool) -> PyResult<()> {
+    check_class(_py, cls, subclass_of, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, protocol, concrete)
+}
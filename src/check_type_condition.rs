@@ -1,45 +1,496 @@
-use std::any::Any;
 use std::env;
-use pyo3::{AsPyPointer, IntoPy, pyfunction, PyObject, PyResult, Python, PyTypeInfo};
-use pyo3::exceptions::{PyAssertionError, PyTypeError};
-use pyo3::ffi::PyObject_IsInstance;
-use pyo3::types::{PyList, PySet, PyType};
+use std::fmt;
+use pyo3::{pyfunction, IntoPy, PyAny, PyObject, PyResult, Python};
+use pyo3::exceptions::{PyArithmeticError, PyAssertionError, PyTypeError, PyValueError};
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyList, PyLong, PySet, PyString, PyTuple, PyType};
 use crate::helper;
 
 
-#[pyfunction]
-#[pyo3(signature = (item, expected_type, /, handle_with=None, message=""))]
-pub unsafe fn check_type(_py: Python, item: PyObject, expected_type: PyObject, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
-    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+/// Options shared by all type matching functions.
+#[derive(Default)]
+pub(crate) struct TypeOptions {
+    /// Check only the first `sample` elements of every container.
+    pub sample: Option<usize>,
+    /// Require exact types instead of accepting subclasses.
+    pub strict: bool,
+}
 
-    if is_disabled {
-        return Ok(());
+/// Describes where and why an item does not match the expected type.
+pub(crate) struct Mismatch {
+    pub path: String,
+    pub expected: String,
+    pub got: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: expected {}, got {}", self.path, self.expected, self.got)
     }
+}
 
-    if PyObject_IsInstance(expected_type.as_ptr(), PyList::type_object(_py).as_ptr()).is_positive() {
-        let list_obj: &PyList = expected_type.downcast(_py).unwrap();
+/// A readable name of a type, or of a type expression such as list[int].
+pub(crate) fn type_name(expected_type: &PyAny) -> String {
+    if expected_type.is_none() {
+        "None".to_string()
+    } else if let Ok(class) = expected_type.downcast::<PyType>() {
+        class.name().map(|name| name.to_string()).unwrap_or_default()
+    } else if expected_type.is_instance_of::<PyList>() || expected_type.is_instance_of::<PyTuple>() {
+        match expected_type.iter() {
+            Ok(types) => { types.flatten().map(type_name).collect::<Vec<_>>().join(" | ") }
+            Err(_) => { expected_type.to_string() }
+        }
+    } else {
+        expected_type.repr().map(|repr| repr.to_string()).unwrap_or_default()
+    }
+}
+
+/// isinstance(), which in strict mode only accepts instances of exactly
+/// `class`. Abstract classes, e.g. collections.abc.Sequence, cannot have
+/// direct instances, so for them strict mode only rejects bools, which are
+/// never accepted in place of other types.
+fn is_instance(_py: Python, item: &PyAny, class: &PyAny, options: &TypeOptions) -> PyResult<bool> {
+    if !options.strict || !class.is_instance_of::<PyType>() {
+        return item.is_instance(class);
+    }
+    if item.get_type().is(class) {
+        return Ok(true);
+    }
+    if class.is_instance(_py.import("abc")?.getattr("ABCMeta")?)? {
+        return Ok(!item.is_instance_of::<PyBool>() && item.is_instance(class)?);
+    }
+    Ok(false)
+}
 
-        for obj in list_obj {
-            let res = PyObject_IsInstance(item.as_ptr(), obj.as_ptr()).is_positive();
-            if res {
-                return Ok(());
+fn mismatch(item: &PyAny, expected_type: &PyAny, path: &str) -> Option<Mismatch> {
+    Some(Mismatch { path: path.to_string(), expected: type_name(expected_type), got: type_name(item.get_type()) })
+}
+
+/// Check whether `item` matches `expected_type` and return the first
+/// mismatch found. `path` names the item and is extended for the elements of
+/// containers.
+///
+/// Besides classes, `expected_type` may be a list, set or tuple of
+/// alternatives, None (for NoneType) or one of the typing constructs
+/// Any, Union, Optional, `X | Y`, Literal and Annotated. The elements of
+/// parameterised generics, e.g. dict[str, list[int]], are checked as well.
+pub(crate) fn find_mismatch(_py: Python, item: &PyAny, expected_type: &PyAny, path: &str, options: &TypeOptions) -> PyResult<Option<Mismatch>> {
+    if expected_type.is_none() {
+        return Ok(if item.is_none() { None } else { mismatch(item, expected_type, path) });
+    }
+
+    if expected_type.is_instance_of::<PyList>()
+        || expected_type.is_instance_of::<PyTuple>()
+        || expected_type.is_instance_of::<PySet>()
+        || expected_type.is_instance_of::<PyFrozenSet>() {
+        return find_alternatives_mismatch(_py, item, expected_type, expected_type, path, options);
+    }
+
+    let typing = _py.import("typing")?;
+
+    if expected_type.is(typing.getattr("Any")?) || expected_type.is_instance(typing.getattr("TypeVar")?)? {
+        return Ok(None);
+    }
+
+    if let Ok(union_type) = _py.import("types")?.getattr("UnionType") {
+        if expected_type.is_instance(union_type)? {
+            return find_alternatives_mismatch(_py, item, expected_type, expected_type.getattr("__args__")?, path, options);
+        }
+    }
+
+    if let Ok(origin) = expected_type.getattr("__origin__") {
+        // Annotated[T, ...] keeps T as its origin and the rest as metadata
+        if expected_type.hasattr("__metadata__")? {
+            return find_mismatch(_py, item, origin, path, options);
+        }
+        if origin.is(typing.getattr("Union")?) {
+            return find_alternatives_mismatch(_py, item, expected_type, expected_type.getattr("__args__")?, path, options);
+        }
+        if typing.getattr("Literal").is_ok_and(|literal| origin.is(literal)) {
+            for value in expected_type.getattr("__args__")?.iter()? {
+                let value = value?;
+                if item.get_type().is(value.get_type()) && item.eq(value)? {
+                    return Ok(None);
+                }
+            }
+            return Ok(Some(Mismatch { path: path.to_string(), expected: type_name(expected_type), got: item.repr()?.to_string() }));
+        }
+        if let Ok(origin) = origin.downcast::<PyType>() {
+            if !is_instance(_py, item, origin, options)? {
+                return Ok(mismatch(item, expected_type, path));
             }
+            return match expected_type.getattr("__args__") {
+                Ok(args) => { find_elements_mismatch(_py, item, origin, args.downcast()?, path, options) }
+                Err(_) => { Ok(None) }
+            };
         }
-    } else if PyObject_IsInstance(expected_type.as_ptr(), PySet::type_object(_py).as_ptr()).is_positive() {
-        let set_obj: &PySet = expected_type.downcast(_py).unwrap();
+    }
 
-        for obj in set_obj {
-            let res = PyObject_IsInstance(item.as_ptr(), obj.as_ptr()).is_positive();
-            if res {
-                return Ok(());
+    if is_instance(_py, item, expected_type, options)? {
+        Ok(None)
+    } else {
+        Ok(mismatch(item, expected_type, path))
+    }
+}
+
+/// Check `item` against several alternative types. If the item matches
+/// none of them, but only one alternative failed because of its elements,
+/// that more detailed mismatch is reported.
+fn find_alternatives_mismatch(_py: Python, item: &PyAny, expected_type: &PyAny, alternatives: &PyAny, path: &str, options: &TypeOptions) -> PyResult<Option<Mismatch>> {
+    let mut nested = Vec::new();
+
+    for alternative in alternatives.iter()? {
+        match find_mismatch(_py, item, alternative?, path, options)? {
+            None => { return Ok(None) }
+            Some(found) => {
+                if found.path != path {
+                    nested.push(found);
+                }
             }
         }
+    }
+
+    if nested.len() == 1 {
+        Ok(nested.pop())
     } else {
-        if PyObject_IsInstance(item.as_ptr(), expected_type.as_ptr()).is_positive() {
-            return Ok(());
+        Ok(mismatch(item, expected_type, path))
+    }
+}
+
+/// Check the elements of a container which is already known to be an
+/// instance of `origin`, the class of a parameterised generic.
+fn find_elements_mismatch(_py: Python, item: &PyAny, origin: &PyType, args: &PyTuple, path: &str, options: &TypeOptions) -> PyResult<Option<Mismatch>> {
+    let abc_mod = _py.import("collections.abc")?;
+    let sample = options.sample.unwrap_or(usize::MAX);
+
+    if origin.is_subclass_of::<PyTuple>()? {
+        let variadic = args.len() == 2 && args.get_item(1)?.is_ellipsis();
+        // tuple[()] has no arguments, typing.Tuple[()] has an empty tuple as the only one
+        let args: &PyTuple = if args.len() == 1 && args.get_item(0)?.eq(PyTuple::empty(_py))? { PyTuple::empty(_py) } else { args };
+
+        if !variadic && item.len()? != args.len() {
+            return Ok(Some(Mismatch {
+                path: path.to_string(),
+                expected: format!("tuple of length {}", args.len()),
+                got: format!("length {}", item.len()?),
+            }));
+        }
+        for (index, element) in item.iter()?.take(sample).enumerate() {
+            let element_type = if variadic { args.get_item(0)? } else { args.get_item(index)? };
+            if let Some(found) = find_mismatch(_py, element?, element_type, &format!("{}[{}]", path, index), options)? {
+                return Ok(Some(found));
+            }
+        }
+        return Ok(None);
+    }
+
+    if origin.is_subclass(abc_mod.getattr("Mapping")?)? && args.len() == 2 {
+        for pair in item.call_method0("items")?.iter()?.take(sample) {
+            let (key, value): (&PyAny, &PyAny) = pair?.extract()?;
+            let key_path = format!("{}[{}]", path, key.repr()?);
+            if let Some(found) = find_mismatch(_py, key, args.get_item(0)?, &key_path, options)? {
+                return Ok(Some(Mismatch { expected: format!("key of type {}", found.expected), ..found }));
+            }
+            if let Some(found) = find_mismatch(_py, value, args.get_item(1)?, &key_path, options)? {
+                return Ok(Some(found));
+            }
+        }
+        return Ok(None);
+    }
+
+    if origin.is_subclass(abc_mod.getattr("Iterable")?)? && args.len() == 1 {
+        // iterators would be consumed by checking their elements
+        if item.iter()?.is(item) {
+            return Ok(None);
+        }
+        let indexed = item.is_instance(abc_mod.getattr("Sequence")?)?;
+        for (index, element) in item.iter()?.take(sample).enumerate() {
+            let element = element?;
+            let element_path = if indexed { format!("{}[{}]", path, index) } else { format!("{}{{{}}}", path, element.repr()?) };
+            if let Some(found) = find_mismatch(_py, element, args.get_item(0)?, &element_path, options)? {
+                return Ok(Some(found));
+            }
         }
     }
 
+    Ok(None)
+}
+
+/// Convert `item` to `expected_type`, returning None if there is no
+/// well-defined conversion. Items which already match are returned as they
+/// are; alternatives are tried in order.
+pub(crate) fn coerce_value(_py: Python, item: &PyAny, expected_type: &PyAny, options: &TypeOptions) -> PyResult<Option<PyObject>> {
+    if find_mismatch(_py, item, expected_type, "", options)?.is_none() {
+        return Ok(Some(item.into_py(_py)));
+    }
+    if expected_type.is_none() {
+        return Ok(None);
+    }
+
+    if expected_type.is_instance_of::<PyList>()
+        || expected_type.is_instance_of::<PyTuple>()
+        || expected_type.is_instance_of::<PySet>()
+        || expected_type.is_instance_of::<PyFrozenSet>() {
+        return coerce_alternatives(_py, item, expected_type, options);
+    }
+
+    let typing = _py.import("typing")?;
+
+    if let Ok(union_type) = _py.import("types")?.getattr("UnionType") {
+        if expected_type.is_instance(union_type)? {
+            return coerce_alternatives(_py, item, expected_type.getattr("__args__")?, options);
+        }
+    }
+
+    if let Ok(origin) = expected_type.getattr("__origin__") {
+        if expected_type.hasattr("__metadata__")? {
+            return coerce_value(_py, item, origin, options);
+        }
+        if origin.is(typing.getattr("Union")?) {
+            return coerce_alternatives(_py, item, expected_type.getattr("__args__")?, options);
+        }
+        if typing.getattr("Literal").is_ok_and(|literal| origin.is(literal)) {
+            for value in expected_type.getattr("__args__")?.iter()? {
+                let value = value?;
+                if let Some(converted) = coerce_value(_py, item, value.get_type(), options)? {
+                    if converted.as_ref(_py).eq(value)? {
+                        return Ok(Some(value.into_py(_py)));
+                    }
+                }
+            }
+            return Ok(None);
+        }
+        if let Ok(origin) = origin.downcast::<PyType>() {
+            return match expected_type.getattr("__args__") {
+                Ok(args) => { coerce_elements(_py, item, origin, args.downcast()?, options) }
+                Err(_) => { Ok(None) }
+            };
+        }
+    }
+
+    match expected_type.downcast::<PyType>() {
+        Ok(class) => { coerce_scalar(_py, item, class) }
+        Err(_) => { Ok(None) }
+    }
+}
+
+fn coerce_alternatives(_py: Python, item: &PyAny, alternatives: &PyAny, options: &TypeOptions) -> PyResult<Option<PyObject>> {
+    for alternative in alternatives.iter()? {
+        if let Some(converted) = coerce_value(_py, item, alternative?, options)? {
+            return Ok(Some(converted));
+        }
+    }
+    Ok(None)
+}
+
+/// Convert the elements of a container and rebuild it as `origin`, or as a
+/// list, set or dict if `origin` is an abstract class.
+fn coerce_elements(_py: Python, item: &PyAny, origin: &PyType, args: &PyTuple, options: &TypeOptions) -> PyResult<Option<PyObject>> {
+    let abc_mod = _py.import("collections.abc")?;
+
+    // strings and bytes are iterable, but not meant to be split into elements
+    if item.is_instance_of::<PyString>() || item.is_instance_of::<PyBytes>() || item.iter().is_err() {
+        return Ok(None);
+    }
+    let is_abstract = origin.is_instance(_py.import("abc")?.getattr("ABCMeta")?)?
+        && _py.import("inspect")?.call_method1("isabstract", (origin,))?.is_true()?;
+
+    if origin.is_subclass_of::<PyTuple>()? {
+        let variadic = args.len() == 2 && args.get_item(1)?.is_ellipsis();
+        let elements: Vec<&PyAny> = item.iter()?.collect::<PyResult<_>>()?;
+        if !variadic && elements.len() != args.len() {
+            return Ok(None);
+        }
+        let mut converted = Vec::with_capacity(elements.len());
+        for (index, element) in elements.into_iter().enumerate() {
+            let element_type = if variadic { args.get_item(0)? } else { args.get_item(index)? };
+            match coerce_value(_py, element, element_type, options)? {
+                Some(value) => { converted.push(value) }
+                None => { return Ok(None) }
+            }
+        }
+        return Ok(Some(PyTuple::new(_py, converted).into_py(_py)));
+    }
+
+    if origin.is_subclass(abc_mod.getattr("Mapping")?)? && args.len() == 2 {
+        if !item.is_instance(abc_mod.getattr("Mapping")?)? {
+            return Ok(None);
+        }
+        let converted = PyDict::new(_py);
+        for pair in item.call_method0("items")?.iter()? {
+            let (key, value): (&PyAny, &PyAny) = pair?.extract()?;
+            match (coerce_value(_py, key, args.get_item(0)?, options)?, coerce_value(_py, value, args.get_item(1)?, options)?) {
+                (Some(key), Some(value)) => { converted.set_item(key, value)? }
+                _ => { return Ok(None) }
+            }
+        }
+        return if is_abstract { Ok(Some(converted.into_py(_py))) } else { Ok(Some(origin.call1((converted,))?.into_py(_py))) };
+    }
+
+    if origin.is_subclass(abc_mod.getattr("Iterable")?)? && args.len() == 1 {
+        let converted = PyList::empty(_py);
+        for element in item.iter()? {
+            match coerce_value(_py, element?, args.get_item(0)?, options)? {
+                Some(value) => { converted.append(value)? }
+                None => { return Ok(None) }
+            }
+        }
+        return if !is_abstract {
+            Ok(Some(origin.call1((converted,))?.into_py(_py)))
+        } else if origin.is_subclass(abc_mod.getattr("Set")?)? {
+            Ok(Some(PySet::new(_py, converted)?.into_py(_py)))
+        } else {
+            Ok(Some(converted.into_py(_py)))
+        };
+    }
+
+    Ok(None)
+}
+
+/// Well-defined conversions between scalar types:
+///     - to bool from "true"/"false", "yes"/"no", "on"/"off", "1"/"0" (in any
+///       case) and from the ints 0 and 1,
+///     - to int from numeric strings and from integer-valued floats,
+///     - to float from ints, other real numbers and numeric strings,
+///     - to Decimal from strings, ints and floats,
+///     - to str from numbers and os.PathLike objects,
+///     - to pathlib paths from str.
+fn coerce_scalar(_py: Python, item: &PyAny, class: &PyType) -> PyResult<Option<PyObject>> {
+    // bools are ints, but converting them to numbers is rarely intended
+    if item.is_instance_of::<PyBool>() {
+        return Ok(None);
+    }
+    let is_str = item.is_instance_of::<PyString>();
+    let numbers_mod = _py.import("numbers")?;
+
+    if class.is_subclass_of::<PyBool>()? {
+        if is_str {
+            return match item.extract::<&str>()?.trim().to_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => { Ok(Some(true.into_py(_py))) }
+                "false" | "no" | "off" | "0" => { Ok(Some(false.into_py(_py))) }
+                _ => { Ok(None) }
+            };
+        }
+        if item.is_instance_of::<PyLong>() {
+            return match item.extract::<i64>() {
+                Ok(1) => { Ok(Some(true.into_py(_py))) }
+                Ok(0) => { Ok(Some(false.into_py(_py))) }
+                _ => { Ok(None) }
+            };
+        }
+        return Ok(None);
+    }
+
+    let convertible = if class.is_subclass_of::<PyLong>()? {
+        is_str || (item.is_instance_of::<PyFloat>() && item.call_method0("is_integer")?.is_true()?)
+    } else if class.is_subclass_of::<PyFloat>()? {
+        is_str || item.is_instance(numbers_mod.getattr("Real")?)?
+    } else if class.is(_py.import("decimal")?.getattr("Decimal")?) {
+        is_str || item.is_instance_of::<PyLong>() || item.is_instance_of::<PyFloat>()
+    } else if class.is_subclass_of::<PyString>()? {
+        if item.is_instance(_py.import("os")?.getattr("PathLike")?)? {
+            return Ok(Some(_py.import("os")?.call_method1("fspath", (item,))?.into_py(_py)));
+        }
+        item.is_instance(numbers_mod.getattr("Number")?)?
+    } else if class.is_subclass(_py.import("pathlib")?.getattr("PurePath")?)? {
+        is_str
+    } else {
+        false
+    };
+
+    if !convertible {
+        return Ok(None);
+    }
+    // floats are converted to Decimal through their shortest representation
+    let source = if item.is_instance_of::<PyFloat>() && class.is(_py.import("decimal")?.getattr("Decimal")?) { item.repr()? } else { item };
+
+    match class.call1((source,)) {
+        Ok(converted) => { Ok(Some(converted.into_py(_py))) }
+        Err(err) => {
+            if err.is_instance_of::<PyValueError>(_py) || err.is_instance_of::<PyArithmeticError>(_py) {
+                Ok(None)
+            } else {
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Check if an item is of the expected type.
+///
+/// Args:
+///     item (Any): object to check
+///     expected_type (type): a class, a list, set or tuple of classes, None,
+///         or a typing construct such as Any, Union[int, str], Optional[int],
+///         int | None, Literal["a", "b"] or Annotated[int, ...]
+///         Parameterised generics, e.g. dict[str, list[int]],
+///         tuple[int, ...] or collections.abc.Sequence[str], are checked
+///         recursively. Elements of iterators are not checked, as that would
+///         consume them.
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to a description of the first mismatch, including the
+///         path to the offending element, e.g.
+///         `item['a'][3]: expected int, got str`.
+///     name (str): how to call the item in the default message
+///     sample (int): check only the first `sample` elements of every
+///         container, e.g. to keep the check fast for huge lists
+///     strict (bool): require exact types, so that neither subclasses nor
+///         bools in place of ints or floats are accepted. Abstract classes
+///         still accept their subclasses, except for bool.
+///     coerce (bool): if the item does not match, try to convert it to the
+///         expected type, e.g. "5" to 5, "1.5" to 1.5, "true" to True or a str
+///         to pathlib.Path. Elements of generics are converted as well, e.g.
+///         ["1", "2"] to [1, 2] for list[int], and alternatives are tried in
+///         order. Bools are never converted to numbers. When checks are
+///         switched off with EASYCHECK_RUN, the conversion is still done, but
+///         an item which cannot be converted is returned as it is.
+///
+/// Returns:
+///     None, if check succeeded.
+///     The item, or the converted item, if coerce is True.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     TypeError by default.
+#[pyfunction]
+#[pyo3(signature = (item, expected_type, /, handle_with=None, message="", *, name="item", sample=None, strict=false, coerce=false))]
+#[allow(clippy::too_many_arguments)]
+pub fn check_type(_py: Python,
+                  item: PyObject,
+                  expected_type: PyObject,
+                  handle_with: Option<&PyType>,
+                  message: Option<&str>,
+                  name: Option<&str>,
+                  sample: Option<usize>,
+                  strict: bool,
+                  coerce: bool) -> PyResult<Option<PyObject>> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    let options = TypeOptions { sample, strict };
+
+    if is_disabled {
+        // the caller relies on the converted value, so only the check is skipped
+        if coerce {
+            let converted = coerce_value(_py, item.as_ref(_py), expected_type.as_ref(_py), &options)?;
+            return Ok(Some(converted.unwrap_or(item)));
+        }
+        return Ok(None);
+    }
+
+    if coerce {
+        if let Some(converted) = coerce_value(_py, item.as_ref(_py), expected_type.as_ref(_py), &options)? {
+            return Ok(Some(converted));
+        }
+    }
+    let found = match find_mismatch(_py, item.as_ref(_py), expected_type.as_ref(_py), name.unwrap_or("item"), &options)? {
+        None => { return Ok(None); }
+        Some(found) => { found.to_string() }
+    };
+    let message = match message {
+        None | Some("") => { Some(found.as_str()) }
+        Some(_) => { message }
+    };
+
     match handle_with {
         None => {
             match message {
@@ -54,7 +505,17 @@ pub unsafe fn check_type(_py: Python, item: PyObject, expected_type: PyObject, h
 }
 
 #[pyfunction]
-#[pyo3(signature = (item, expected_type, /, handle_with=None, message=""))]
-pub unsafe fn assert_type(_py: Python, item: PyObject, expected_type: PyObject, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
-    check_type(_py, item, expected_type, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
-}
\ No newline at end of file
+#[allow(unused_variables)]
+#[pyo3(signature = (item, expected_type, /, handle_with=None, message="", *, name="item", sample=None, strict=false, coerce=false))]
+#[allow(clippy::too_many_arguments)]
+pub fn assert_type(_py: Python,
+                   item: PyObject,
+                   expected_type: PyObject,
+                   handle_with: Option<&PyType>,
+                   message: Option<&str>,
+                   name: Option<&str>,
+                   sample: Option<usize>,
+                   strict: bool,
+                   coerce: bool) -> PyResult<Option<PyObject>> {
+    check_type(_py, item, expected_type, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, name, sample, strict, coerce)
+}
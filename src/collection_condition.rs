@@ -0,0 +1,275 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::basic::CompareOp;
+use pyo3::buffer::{Element, PyBuffer};
+use pyo3::exceptions::{PyAssertionError, PyValueError};
+use pyo3::types::{PyDict, PyFloat, PyList, PyLong, PyTuple, PyType};
+
+use crate::helper;
+
+/// Check that the elements of an iterable are unique.
+///
+/// Args:
+///     items (Iterable): elements to check, which must be hashable
+///     key (Callable): function applied to every element, whose results
+///         must be unique instead of the elements themselves
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the duplicated values.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. The exception has a `duplicates` attribute, a
+///     dict mapping every duplicated value (or key) to the indices at which
+///     it occurs.
+///
+/// >>> check_unique([3, 1, 3, 2, 1])
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: duplicate values: 3 at [0, 2], 1 at [1, 4]
+#[pyfunction]
+#[pyo3(signature = (items, /, key=None, handle_with=None, message=None))]
+pub fn check_unique(_py: Python,
+                    items: &PyAny,
+                    key: Option<&PyAny>,
+                    handle_with: Option<&PyType>,
+                    message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let positions = PyDict::new(_py);
+    let mut has_duplicates = false;
+
+    for (idx, element) in items.iter()?.enumerate() {
+        let element = element?;
+        let value = match key {
+            None => { element }
+            Some(key) => { key.call1((element,))? }
+        };
+        match positions.get_item(value) {
+            None => { positions.set_item(value, PyList::new(_py, [idx]))? }
+            Some(indices) => {
+                indices.downcast::<PyList>()?.append(idx)?;
+                has_duplicates = true;
+            }
+        }
+    }
+
+    if !has_duplicates {
+        return Ok(());
+    }
+
+    let duplicates = PyDict::new(_py);
+    let mut described: Vec<String> = Vec::new();
+    for (value, indices) in positions.iter() {
+        if indices.len()? > 1 {
+            duplicates.set_item(value, indices)?;
+            described.push(format!("{} at {}", value.repr()?, indices.repr()?));
+        }
+    }
+
+    let default_message = format!("duplicate values: {}", described.join(", "));
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyValueError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("duplicates", duplicates.into_py(_py))]))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (items, /, key=None, handle_with=None, message=None))]
+pub fn assert_unique(_py: Python,
+                     items: &PyAny,
+                     key: Option<&PyAny>,
+                     handle_with: Option<&PyType>,
+                     message: Option<&str>) -> PyResult<()> {
+    check_unique(_py, items, key, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// The first position at which two neighbouring elements are out of order,
+/// together with the two elements.
+struct Violation {
+    index: usize,
+    previous: PyObject,
+    current: PyObject,
+}
+
+fn is_ordered<T: PartialOrd>(previous: &T, current: &T, strict: bool, reverse: bool) -> bool {
+    match (strict, reverse) {
+        (false, false) => { previous <= current }
+        (true, false) => { previous < current }
+        (false, true) => { previous >= current }
+        (true, true) => { previous > current }
+    }
+}
+
+fn find_native_violation<T: PartialOrd + Copy + IntoPy<PyObject>>(_py: Python, values: &[T], strict: bool, reverse: bool) -> Option<Violation> {
+    values.windows(2)
+        .position(|pair| !is_ordered(&pair[0], &pair[1], strict, reverse))
+        .map(|idx| Violation { index: idx + 1, previous: values[idx].into_py(_py), current: values[idx + 1].into_py(_py) })
+}
+
+/// Scan a one-dimensional buffer of `T`, or return None if `items` does not
+/// expose such a buffer.
+fn find_buffer_violation<T: Element + PartialOrd + Copy + IntoPy<PyObject>>(_py: Python, items: &PyAny, strict: bool, reverse: bool) -> PyResult<Option<Option<Violation>>> {
+    let buffer = match PyBuffer::<T>::get(items) {
+        Ok(buffer) if buffer.dimensions() == 1 => { buffer }
+        _ => { return Ok(None) }
+    };
+    Ok(Some(find_native_violation(_py, &buffer.to_vec(_py)?, strict, reverse)))
+}
+
+/// Scan lists and tuples of only ints or only floats, and numeric buffers,
+/// without comparing Python objects. Returns None if there is no fast path for
+/// `items`.
+fn find_fast_violation(_py: Python, items: &PyAny, strict: bool, reverse: bool) -> PyResult<Option<Option<Violation>>> {
+    let elements = match (items.downcast::<PyList>(), items.downcast::<PyTuple>()) {
+        (Ok(list), _) => { Some(list.as_sequence()) }
+        (_, Ok(tuple)) => { Some(tuple.as_sequence()) }
+        _ => { None }
+    };
+    if let Some(elements) = elements {
+        let elements: Vec<&PyAny> = elements.iter()?.collect::<PyResult<_>>()?;
+        if elements.iter().all(|element| element.is_exact_instance_of::<PyLong>()) {
+            if let Ok(values) = elements.iter().map(|element| element.extract::<i64>()).collect::<PyResult<Vec<_>>>() {
+                return Ok(Some(find_native_violation(_py, &values, strict, reverse)));
+            }
+        } else if elements.iter().all(|element| element.is_exact_instance_of::<PyFloat>()) {
+            let values = elements.iter().map(|element| element.extract::<f64>()).collect::<PyResult<Vec<_>>>()?;
+            return Ok(Some(find_native_violation(_py, &values, strict, reverse)));
+        }
+        return Ok(None);
+    }
+
+    if let Some(found) = find_buffer_violation::<f64>(_py, items, strict, reverse)? { return Ok(Some(found)) }
+    if let Some(found) = find_buffer_violation::<f32>(_py, items, strict, reverse)? { return Ok(Some(found)) }
+    if let Some(found) = find_buffer_violation::<i64>(_py, items, strict, reverse)? { return Ok(Some(found)) }
+    if let Some(found) = find_buffer_violation::<i32>(_py, items, strict, reverse)? { return Ok(Some(found)) }
+    if let Some(found) = find_buffer_violation::<u64>(_py, items, strict, reverse)? { return Ok(Some(found)) }
+    if let Some(found) = find_buffer_violation::<u32>(_py, items, strict, reverse)? { return Ok(Some(found)) }
+    Ok(None)
+}
+
+fn find_violation(_py: Python, items: &PyAny, key: Option<&PyAny>, strict: bool, reverse: bool) -> PyResult<Option<Violation>> {
+    let operator = match (strict, reverse) {
+        (false, false) => { CompareOp::Le }
+        (true, false) => { CompareOp::Lt }
+        (false, true) => { CompareOp::Ge }
+        (true, true) => { CompareOp::Gt }
+    };
+    let mut previous: Option<(&PyAny, &PyAny)> = None;
+
+    for (idx, element) in items.iter()?.enumerate() {
+        let element = element?;
+        let value = match key {
+            None => { element }
+            Some(key) => { key.call1((element,))? }
+        };
+        if let Some((previous_element, previous_value)) = previous {
+            if !previous_value.rich_compare(value, operator)?.is_true()? {
+                return Ok(Some(Violation { index: idx, previous: previous_element.into_py(_py), current: element.into_py(_py) }));
+            }
+        }
+        previous = Some((element, value));
+    }
+    Ok(None)
+}
+
+/// Check that the elements of an iterable are sorted.
+///
+/// Lists and tuples of only ints or only floats, and one-dimensional numeric
+/// buffers such as array.array or numpy arrays, are scanned natively.
+///
+/// Args:
+///     items (Iterable): elements to check
+///     key (Callable): function applied to every element, whose results
+///         are compared instead of the elements themselves
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means describing the first violation.
+///     strict (bool): forbid equal neighbours
+///     reverse (bool): require decreasing instead of increasing order
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. The exception has an `index` attribute, the
+///     index of the first element which is out of order.
+///
+/// >>> check_sorted([1, 2, 2, 5, 4], strict=True)
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: items are not strictly increasing: [1] 2 is followed by [2] 2
+#[pyfunction]
+#[pyo3(signature = (items, /, key=None, handle_with=None, message=None, *, strict=false, reverse=false))]
+pub fn check_sorted(_py: Python,
+                    items: &PyAny,
+                    key: Option<&PyAny>,
+                    handle_with: Option<&PyType>,
+                    message: Option<&str>,
+                    strict: bool,
+                    reverse: bool) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let fast = match key {
+        None => { find_fast_violation(_py, items, strict, reverse)? }
+        Some(_) => { None }
+    };
+    let violation = match fast {
+        Some(found) => { found }
+        None => { find_violation(_py, items, key, strict, reverse)? }
+    };
+    let violation = match violation {
+        None => { return Ok(()) }
+        Some(violation) => { violation }
+    };
+
+    let order = match (strict, reverse) {
+        (false, false) => { "non-decreasing" }
+        (true, false) => { "strictly increasing" }
+        (false, true) => { "non-increasing" }
+        (true, true) => { "strictly decreasing" }
+    };
+    let default_message = format!("items are not {}: [{}] {} is followed by [{}] {}",
+                                  order, violation.index - 1, violation.previous.as_ref(_py).repr()?,
+                                  violation.index, violation.current.as_ref(_py).repr()?);
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyValueError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("index", violation.index.into_py(_py))]))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (items, /, key=None, handle_with=None, message=None, *, strict=false, reverse=false))]
+pub fn assert_sorted(_py: Python,
+                     items: &PyAny,
+                     key: Option<&PyAny>,
+                     handle_with: Option<&PyType>,
+                     message: Option<&str>,
+                     strict: bool,
+                     reverse: bool) -> PyResult<()> {
+    check_sorted(_py, items, key, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, strict, reverse)
+}
@@ -0,0 +1,156 @@
+use std::env;
+use std::str::FromStr;
+
+use pyo3::prelude::*;
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyValueError};
+use pyo3::types::{PyDateTime, PyDelta, PyString, PyType};
+
+use crate::helper;
+use crate::limit_condition::{Dimension, LimitError, Quantity};
+
+/// max_age as a timedelta; numbers are seconds and strings durations such
+/// as "2h" or "1d 12h".
+fn max_age_delta<'py>(_py: Python<'py>, max_age: &'py PyAny) -> PyResult<&'py PyAny> {
+    if max_age.is_instance_of::<PyDelta>() {
+        return Ok(max_age);
+    }
+    let quantity = match max_age.downcast::<PyString>() {
+        Ok(text) => { Quantity::from_str(text.to_str()?).map_err(PyValueError::new_err)? }
+        Err(_) => { Quantity { value: max_age.extract()?, dimension: None } }
+    };
+    if quantity.dimension == Some(Dimension::Size) {
+        return Err(PyValueError::new_err(format!("max_age must be a duration, got {}", max_age.repr()?)));
+    }
+    _py.import("datetime")?.getattr("timedelta")?.call1((0, quantity.value))
+}
+
+fn is_aware(value: &PyAny) -> PyResult<bool> {
+    Ok(!value.call_method0("utcoffset")?.is_none())
+}
+
+/// Check a datetime: that it is timezone-aware, within a window, not in the
+/// future or not older than a given age.
+///
+/// As in check_if_in_limits, include_equal decides whether a value equal to
+/// a limit passes.
+///
+/// Args:
+///     value (datetime.datetime): datetime to check
+///     not_before (datetime.datetime): earliest allowed datetime
+///     not_after (datetime.datetime): latest allowed datetime
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means describing what is wrong.
+///     require_tz (bool): require a timezone-aware datetime
+///     max_age (timedelta, float or str): maximum time between value and
+///         now, as a timedelta, a number of seconds or a duration such as
+///         "2h" or "1d 12h"
+///     allow_future (bool): accept datetimes after now
+///     include_equal (bool): accept datetimes equal to a limit. Defaults to
+///         True.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     LimitError by default.
+///
+/// >>> check_datetime(datetime(2024, 1, 1), require_tz=True)
+/// Traceback (most recent call last):
+///     ...
+/// LimitError: 2024-01-01 00:00:00 is not timezone-aware
+#[pyfunction]
+#[pyo3(signature = (value, /, not_before=None, not_after=None, handle_with=None, message=None, *, require_tz=false, max_age=None, allow_future=true, include_equal=true))]
+#[allow(clippy::too_many_arguments)]
+pub fn check_datetime(_py: Python,
+                      value: &PyAny,
+                      not_before: Option<&PyAny>,
+                      not_after: Option<&PyAny>,
+                      handle_with: Option<&PyType>,
+                      message: Option<&str>,
+                      require_tz: bool,
+                      max_age: Option<&PyAny>,
+                      allow_future: bool,
+                      include_equal: bool) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    if !value.is_instance_of::<PyDateTime>() {
+        return Err(PyTypeError::new_err(format!("expected a datetime, got '{}'", value.get_type().name()?)));
+    }
+
+    let mut problem: Option<String> = None;
+
+    if require_tz && !is_aware(value)? {
+        problem = Some(format!("{} is not timezone-aware", value));
+    }
+    if problem.is_none() {
+        if let Some(not_before) = not_before {
+            let operator = if include_equal { CompareOp::Lt } else { CompareOp::Le };
+            if value.rich_compare(not_before, operator)?.is_true()? {
+                problem = Some(format!("{} is {} {}", value, if include_equal { "before" } else { "not after" }, not_before));
+            }
+        }
+    }
+    if problem.is_none() {
+        if let Some(not_after) = not_after {
+            let operator = if include_equal { CompareOp::Gt } else { CompareOp::Ge };
+            if value.rich_compare(not_after, operator)?.is_true()? {
+                problem = Some(format!("{} is {} {}", value, if include_equal { "after" } else { "not before" }, not_after));
+            }
+        }
+    }
+    if problem.is_none() && (!allow_future || max_age.is_some()) {
+        let datetime = _py.import("datetime")?;
+        let now = if is_aware(value)? {
+            datetime.getattr("datetime")?.call_method1("now", (datetime.getattr("timezone")?.getattr("utc")?,))?
+        } else {
+            datetime.getattr("datetime")?.call_method0("now")?
+        };
+        if !allow_future && value.rich_compare(now, CompareOp::Gt)?.is_true()? {
+            problem = Some(format!("{} is in the future", value));
+        } else if let Some(max_age) = max_age {
+            let max_age = max_age_delta(_py, max_age)?;
+            let age = now.call_method1("__sub__", (value,))?;
+            let operator = if include_equal { CompareOp::Gt } else { CompareOp::Ge };
+            if age.rich_compare(max_age, operator)?.is_true()? {
+                problem = Some(format!("{} is older than {} (age {})", value, max_age, age));
+            }
+        }
+    }
+
+    let default_message = match problem {
+        None => { return Ok(()) }
+        Some(problem) => { problem }
+    };
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    match handle_with {
+        None => { Err(helper::check_handle_warning_with(Some(_py.get_type::<LimitError>()), Some(message))) }
+        Some(_) => { Err(helper::check_handle_warning_with(handle_with, Some(message))) }
+    }
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (value, /, not_before=None, not_after=None, handle_with=None, message=None, *, require_tz=false, max_age=None, allow_future=true, include_equal=true))]
+pub fn assert_datetime(_py: Python,
+                       value: &PyAny,
+                       not_before: Option<&PyAny>,
+                       not_after: Option<&PyAny>,
+                       handle_with: Option<&PyType>,
+                       message: Option<&str>,
+                       require_tz: bool,
+                       max_age: Option<&PyAny>,
+                       allow_future: bool,
+                       include_equal: bool) -> PyResult<()> {
+    check_datetime(_py, value, not_before, not_after, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, require_tz, max_age, allow_future, include_equal)
+}
@@ -0,0 +1,114 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyValueError};
+use pyo3::types::{PyString, PyType};
+
+use crate::helper;
+
+/// Look up the member of `enum_class` with the given value or name.
+fn resolve_member<'py>(_py: Python<'py>, value: &'py PyAny, enum_class: &'py PyType, by: &str) -> PyResult<Option<&'py PyAny>> {
+    if value.is_instance(enum_class)? {
+        return Ok(Some(value));
+    }
+    match by {
+        "value" => {
+            match enum_class.call1((value,)) {
+                Ok(member) => { Ok(Some(member)) }
+                Err(err) if err.is_instance_of::<PyValueError>(_py) => { Ok(None) }
+                Err(err) => { Err(err) }
+            }
+        }
+        _ => {
+            if !value.is_instance_of::<PyString>() {
+                return Ok(None);
+            }
+            Ok(enum_class.getattr("__members__")?.get_item(value).ok())
+        }
+    }
+}
+
+/// Check that a value identifies a member of an Enum class.
+///
+/// Args:
+///     value (Any): value (or name) of the member
+///     enum_class (type): subclass of enum.Enum
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the valid options.
+///     by (str): whether value is the "value" or the "name" of the member
+///
+/// Returns:
+///     The resolved Enum member, if check succeeded. When checks are switched
+///     off with EASYCHECK_RUN, a value which cannot be resolved is returned
+///     as it is.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default.
+///
+/// >>> class Color(Enum):
+/// ...     RED = 1
+/// ...     GREEN = 2
+/// >>> check_enum(2, Color)
+/// <Color.GREEN: 2>
+/// >>> check_enum("BLUE", Color, by="name")
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: 'BLUE' is not a valid Color name; valid names: 'RED', 'GREEN'
+#[pyfunction]
+#[pyo3(signature = (value, enum_class, /, handle_with=None, message=None, *, by="value"))]
+pub fn check_enum(_py: Python,
+                  value: &PyAny,
+                  enum_class: &PyAny,
+                  handle_with: Option<&PyType>,
+                  message: Option<&str>,
+                  by: &str) -> PyResult<PyObject> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    let enum_class: &PyType = match enum_class.downcast::<PyType>() {
+        Ok(class) if class.is_subclass(_py.import("enum")?.getattr("Enum")?)? => { class }
+        _ => { return Err(PyTypeError::new_err("enum_class must be a subclass of enum.Enum")) }
+    };
+
+    if by != "value" && by != "name" {
+        return Err(PyValueError::new_err("by can only be `value` or `name`"));
+    }
+
+    if let Some(member) = resolve_member(_py, value, enum_class, by)? {
+        return Ok(member.into_py(_py));
+    }
+
+    if is_disabled {
+        return Ok(value.into_py(_py));
+    }
+
+    let mut options: Vec<String> = Vec::new();
+    for member in enum_class.iter()? {
+        let member = member?;
+        let option = if by == "name" { member.getattr("name")? } else { member.getattr("value")? };
+        options.push(option.repr()?.to_string());
+    }
+    let default_message = format!("{} is not a valid {} {}; valid {}s: {}",
+                                  value.repr()?, enum_class.name()?, by, by, options.join(", "));
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+
+    match handle_with {
+        None => { Err(PyValueError::new_err(message.to_string())) }
+        Some(_) => { Err(helper::check_handle_warning_with(handle_with, Some(message))) }
+    }
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (value, enum_class, /, handle_with=None, message=None, *, by="value"))]
+pub fn assert_enum(_py: Python,
+                   value: &PyAny,
+                   enum_class: &PyAny,
+                   handle_with: Option<&PyType>,
+                   message: Option<&str>,
+                   by: &str) -> PyResult<PyObject> {
+    check_enum(_py, value, enum_class, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, by)
+}
@@ -0,0 +1,266 @@
+use std::env;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyValueError};
+use pyo3::types::{PyString, PyType};
+use regex::Regex;
+
+use crate::helper;
+
+/// Names of the formats check_format knows.
+const FORMATS: [&str; 10] = [
+    "email", "url", "uuid", "ipv4", "ipv6", "cidr", "iso_date", "iso_datetime", "semver", "hostname",
+];
+
+/// The regular expression recommended by the Semantic Versioning 2.0.0 spec.
+const SEMVER: &str = r"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-((?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$";
+
+/// The local part of an email address, as a dot-atom (RFC 5322).
+const EMAIL_LOCAL: &str = r"^[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+)*$";
+
+fn regex(cell: &'static OnceLock<Regex>, source: &str) -> &'static Regex {
+    cell.get_or_init(|| Regex::new(source).expect("valid built-in pattern"))
+}
+
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+    !value.is_empty() && value.len() <= 253 && value.split('.').all(|label| {
+        !label.is_empty() && label.len() <= 63 && !label.starts_with('-') && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+fn is_email(value: &str) -> bool {
+    static LOCAL: OnceLock<Regex> = OnceLock::new();
+    match value.rsplit_once('@') {
+        Some((local, domain)) => {
+            local.len() <= 64 && regex(&LOCAL, EMAIL_LOCAL).is_match(local) && domain.contains('.') && is_hostname(domain)
+        }
+        None => { false }
+    }
+}
+
+fn is_url(value: &str) -> bool {
+    let (scheme, rest) = match value.split_once("://") {
+        Some(parts) => { parts }
+        None => { return false }
+    };
+    let mut scheme_chars = scheme.chars();
+    if !scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        || !scheme_chars.all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c)) {
+        return false;
+    }
+    if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+
+    let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+    let (host, port) = if let Some(ipv6) = host_port.strip_prefix('[') {
+        match ipv6.split_once(']') {
+            Some((address, port)) if Ipv6Addr::from_str(address).is_ok() => {
+                match port.strip_prefix(':') {
+                    Some(port) => { (None, Some(port)) }
+                    None if port.is_empty() => { (None, None) }
+                    None => { return false }
+                }
+            }
+            _ => { return false }
+        }
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => { (Some(host), Some(port)) }
+            None => { (Some(host_port), None) }
+        }
+    };
+    let port_ok = port.is_none_or(|port| port.chars().all(|c| c.is_ascii_digit()) && port.parse::<u16>().is_ok());
+    let host_ok = host.is_none_or(|host| Ipv4Addr::from_str(host).is_ok() || is_hostname(host));
+    port_ok && host_ok
+}
+
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.len() == 5
+        && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn is_cidr(value: &str) -> bool {
+    match value.split_once('/') {
+        Some((address, prefix)) => {
+            let max_prefix = match IpAddr::from_str(address) {
+                Ok(IpAddr::V4(_)) => { 32 }
+                Ok(IpAddr::V6(_)) => { 128 }
+                Err(_) => { return false }
+            };
+            prefix.chars().all(|c| c.is_ascii_digit()) && prefix.parse::<u8>().is_ok_and(|prefix| prefix <= max_prefix)
+        }
+        None => { false }
+    }
+}
+
+/// Parse a fixed number of ASCII digits.
+fn digits(value: &str, len: usize) -> Option<u32> {
+    (value.len() == len && value.chars().all(|c| c.is_ascii_digit())).then(|| value.parse().ok()).flatten()
+}
+
+fn is_iso_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    let (year, month, day) = match parts[..] {
+        [year, month, day] => {
+            match (digits(year, 4), digits(month, 2), digits(day, 2)) {
+                (Some(year), Some(month), Some(day)) => { (year, month, day) }
+                _ => { return false }
+            }
+        }
+        _ => { return false }
+    };
+    let is_leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => { 31 }
+        4 | 6 | 9 | 11 => { 30 }
+        2 => { if is_leap { 29 } else { 28 } }
+        _ => { return false }
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+/// HH:MM or HH:MM:SS with an optional fraction of a second.
+fn is_iso_time(value: &str) -> bool {
+    let (time, fraction) = value.split_once(['.', ',']).unwrap_or((value, "0"));
+    if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let parts: Vec<Option<u32>> = time.split(':').map(|part| digits(part, 2)).collect();
+    match parts[..] {
+        [Some(hour), Some(minute)] => { value == time && hour <= 23 && minute <= 59 }
+        [Some(hour), Some(minute), Some(second)] => { hour <= 23 && minute <= 59 && second <= 59 }
+        _ => { false }
+    }
+}
+
+fn is_iso_datetime(value: &str) -> bool {
+    let (date, time) = match value.split_once(['T', 't', ' ']) {
+        Some(parts) => { parts }
+        None => { return false }
+    };
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, None)
+    } else {
+        match time.rfind(['+', '-']) {
+            Some(idx) => { (&time[..idx], Some(&time[idx + 1..])) }
+            None => { (time, None) }
+        }
+    };
+    let offset_ok = offset.is_none_or(|offset| {
+        let (hours, minutes) = match offset.split_once(':') {
+            Some(parts) => { parts }
+            None if offset.len() == 4 && offset.is_ascii() => { offset.split_at(2) }
+            None => { return false }
+        };
+        digits(hours, 2).is_some_and(|hours| hours <= 23) && digits(minutes, 2).is_some_and(|minutes| minutes <= 59)
+    });
+    is_iso_date(date) && is_iso_time(time) && offset_ok
+}
+
+fn is_valid(value: &str, format: &str) -> bool {
+    static SEMVER_REGEX: OnceLock<Regex> = OnceLock::new();
+    match format {
+        "email" => { is_email(value) }
+        "url" => { is_url(value) }
+        "uuid" => { is_uuid(value) }
+        "ipv4" => { Ipv4Addr::from_str(value).is_ok() }
+        "ipv6" => { Ipv6Addr::from_str(value).is_ok() }
+        "cidr" => { is_cidr(value) }
+        "iso_date" => { is_iso_date(value) }
+        "iso_datetime" => { is_iso_datetime(value) }
+        "semver" => { regex(&SEMVER_REGEX, SEMVER).is_match(value) }
+        _ => { is_hostname(value) }
+    }
+}
+
+/// Check that a string is in a well-known format.
+///
+/// The formats are checked natively, without network access:
+///     email: an address such as "jane.doe@example.com"
+///     url: an absolute URL with a scheme and a host, e.g.
+///         "https://example.com:8080/path?q=1"
+///     uuid: "12345678-1234-5678-1234-567812345678", in any case
+///     ipv4, ipv6: an IP address
+///     cidr: an IPv4 or IPv6 network, e.g. "10.0.0.0/8"
+///     iso_date: a valid calendar date, "YYYY-MM-DD"
+///     iso_datetime: an ISO 8601 date and time, with optional seconds,
+///         fraction and UTC offset, e.g. "2024-02-29T13:45:00.123+01:00"
+///     semver: a Semantic Versioning 2.0.0 version, e.g. "1.4.0-rc.1"
+///     hostname: a host name as in RFC 1123
+///
+/// Args:
+///     value (str): string to check
+///     format (str): name of the format, one of the above
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming the value and the format.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default.
+///
+/// >>> check_format("2023-02-29", "iso_date")
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: '2023-02-29' is not a valid iso_date
+#[pyfunction]
+#[pyo3(signature = (value, format, /, handle_with=None, message=None))]
+pub fn check_format(_py: Python,
+                    value: &PyAny,
+                    format: &str,
+                    handle_with: Option<&PyType>,
+                    message: Option<&str>) -> PyResult<()> {
+    if !FORMATS.contains(&format) {
+        let mut msg = format!("unknown format '{}'", format);
+        if let Some(suggested) = helper::closest_match(format, FORMATS) {
+            msg.push_str(&format!("; did you mean '{}'?", suggested));
+        }
+        return Err(PyValueError::new_err(msg));
+    }
+
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let text = match value.downcast::<PyString>() {
+        Ok(text) => { text.to_str()? }
+        Err(_) => { return Err(PyTypeError::new_err(format!("expected str, got '{}'", value.get_type().name()?))) }
+    };
+    if is_valid(text, format) {
+        return Ok(());
+    }
+
+    let default_message = format!("{} is not a valid {}", value.repr()?, format);
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    match handle_with {
+        None => { Err(PyValueError::new_err(message.to_string())) }
+        Some(_) => { Err(helper::check_handle_warning_with(handle_with, Some(message))) }
+    }
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (value, format, /, handle_with=None, message=None))]
+pub fn assert_format(_py: Python,
+                     value: &PyAny,
+                     format: &str,
+                     handle_with: Option<&PyType>,
+                     message: Option<&str>) -> PyResult<()> {
+    check_format(_py, value, format, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
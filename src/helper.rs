@@ -1,5 +1,5 @@
 use pyo3::exceptions::{PyAssertionError, PyUserWarning, PyWarning};
-use pyo3::{PyErr};
+use pyo3::{PyErr, PyObject, Python};
 use pyo3::types::PyType;
 
 pub fn check_handle_with(handle_with: Option<&PyType>, message: Option<&str>) -> PyErr {
@@ -45,3 +45,44 @@ pub fn check_handle_warning_with(handle_with: Option<&PyType>, message: Option<&
         }
     }
 }
+
+/// Attach additional information about a failed check to the error, so that it
+/// can be inspected by the caller, e.g. `error.offenders`.
+pub fn with_attributes(_py: Python, err: PyErr, attributes: Vec<(&str, PyObject)>) -> PyErr {
+    let value = err.value(_py);
+    for (name, attribute) in attributes {
+        // built-in exceptions accept new attributes, so this cannot fail in practice
+        let _ = value.setattr(name, attribute);
+    }
+    err
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The candidate closest to `target` (ignoring case), if it is similar
+/// enough to be a likely typo: at most a third of its characters differ.
+pub fn closest_match<'a>(target: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let target_lower = target.to_lowercase();
+    let max_distance = (target.chars().count() / 3).max(1);
+
+    candidates.into_iter()
+        .filter(|candidate| *candidate != target)
+        .map(|candidate| (edit_distance(&target_lower, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
@@ -27,6 +27,7 @@ pub fn check_if(_py: Python, condition: Py<PyBool>, handle_with: Option<&PyType>
 }
 
 #[pyfunction]
+#[allow(unused_variables)]
 pub fn assert_if(_py: Python, condition: Py<PyBool>, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
     check_if(_py, condition, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
 }
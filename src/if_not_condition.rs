@@ -26,6 +26,7 @@ pub fn check_if_not(_py: Python, condition: Py<PyBool>, handle_with: Option<&PyT
 }
 
 #[pyfunction]
+#[allow(unused_variables)]
 pub fn assert_if_not(_py: Python, condition: Py<PyBool>, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
     check_if_not(_py, condition, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
 }
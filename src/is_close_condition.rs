@@ -97,6 +97,7 @@ pub fn check_if_isclose(_py: Python,
 }
 
 #[pyfunction]
+#[allow(unused_variables)]
 #[pyo3(signature = (x, y, /, *, handle_with=None, message="", rel_tol=0.0000000001_f64, abs_tol=0.0_f64))]
 pub fn assert_if_isclose(_py: Python,
                         x: Py<PyFloat>,
@@ -1,7 +1,8 @@
 use std::env;
 use pyo3::prelude::*;
-use pyo3::exceptions::{PyAssertionError, PyBaseException, PyTypeError};
-use pyo3::types::{PyList, PyType};
+use pyo3::exceptions::{PyAssertionError, PyBaseException, PyTypeError, PyValueError};
+use pyo3::types::{PyBytes, PyDict, PyList, PyString, PyType};
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::helper;
 
@@ -14,68 +15,379 @@ fn raise_length_error_if(_py: Python, handle_with: Option<&PyType>, message: Opt
     }
 }
 
+/// Comparison operators which can be given by name instead of a callable.
+#[derive(Clone, Copy)]
+enum Comparison {
+    Lt,
+    Le,
+    Eq,
+    Ne,
+    Ge,
+    Gt,
+}
+
+impl Comparison {
+    fn parse(operator: &str) -> PyResult<Comparison> {
+        match operator {
+            "<" | "lt" => Ok(Comparison::Lt),
+            "<=" | "le" => Ok(Comparison::Le),
+            "==" | "eq" => Ok(Comparison::Eq),
+            "!=" | "ne" => Ok(Comparison::Ne),
+            ">=" | "ge" => Ok(Comparison::Ge),
+            ">" | "gt" => Ok(Comparison::Gt),
+            _ => Err(PyValueError::new_err(format!("unknown comparison operator '{}'", operator))),
+        }
+    }
+
+    fn compare(self, length: i128, expected_length: i128) -> bool {
+        match self {
+            Comparison::Lt => length < expected_length,
+            Comparison::Le => length <= expected_length,
+            Comparison::Eq => length == expected_length,
+            Comparison::Ne => length != expected_length,
+            Comparison::Ge => length >= expected_length,
+            Comparison::Gt => length > expected_length,
+        }
+    }
+}
+
+/// Compare `length` with `expected_length` using `operator`, which is either
+/// the name of a comparison operator or a callable whose return value decides
+/// about the result.
+fn compare_length(_py: Python, length: usize, expected_length: &PyAny, operator: Option<&PyAny>) -> PyResult<bool> {
+    match operator {
+        None => {
+            Ok(length.to_object(_py).as_ref(_py).eq(expected_length).unwrap_or(false))
+        }
+        Some(op) => {
+            if let Ok(name) = op.downcast::<PyString>() {
+                let expected: i128 = expected_length.extract()?;
+                Ok(Comparison::parse(name.to_str()?)?.compare(length as i128, expected))
+            } else if !op.is_callable() {
+                Err(PyTypeError::new_err(format!("'{}' object is not callable", op.get_type().name().unwrap_or(""))))
+            } else {
+                op.call1((length, expected_length))?.is_true()
+            }
+        }
+    }
+}
+
+/// Length of a str or bytes object measured in `unit`: UTF-8 bytes, code
+/// points ("chars") or user-perceived characters ("graphemes").
+fn text_length(item: &PyAny, unit: &str) -> PyResult<usize> {
+    if unit != "bytes" && unit != "chars" && unit != "graphemes" {
+        return Err(PyValueError::new_err(format!("unit can only be `bytes`, `chars` or `graphemes`, not '{}'", unit)));
+    }
+
+    let text = if let Ok(string) = item.downcast::<PyString>() {
+        string.to_str()?
+    } else if let Ok(bytes) = item.downcast::<PyBytes>() {
+        if unit == "bytes" {
+            return Ok(bytes.as_bytes().len());
+        }
+        std::str::from_utf8(bytes.as_bytes())
+            .map_err(|err| PyValueError::new_err(format!("bytes are not valid UTF-8: {}", err)))?
+    } else {
+        return Err(PyTypeError::new_err(format!("unit can only be used with str or bytes, not '{}'", item.get_type().name().unwrap_or(""))));
+    };
+
+    match unit {
+        "bytes" => { Ok(text.len()) }
+        "chars" => { Ok(text.chars().count()) }
+        _ => { Ok(text.graphemes(true).count()) }
+    }
+}
+
+/// Count the items of an iterable by consuming it, stopping once `stop_at`
+/// items were seen. Consumed items are appended to `consumed`, if given.
+fn consume_and_count(item: &PyAny, stop_at: Option<usize>, consumed: Option<&PyList>) -> PyResult<usize> {
+    let mut count = 0;
+
+    for element in item.iter()? {
+        let element = element?;
+        if let Some(list) = consumed {
+            list.append(element)?;
+        }
+        count += 1;
+        if stop_at.is_some_and(|stop| count >= stop) {
+            break;
+        }
+    }
+    Ok(count)
+}
+
+/// The number of items after which counting can stop, as the check is bound
+/// to fail once it is exceeded.
+fn count_limit(expected_length: Option<&PyAny>, operator: Option<&PyAny>, max_length: Option<usize>) -> Option<usize> {
+    let from_expected = match (expected_length, operator) {
+        (Some(expected), None) => expected.extract::<usize>().ok(),
+        _ => None,
+    };
+    [from_expected, max_length].into_iter().flatten().min().map(|limit| limit + 1)
+}
+
+/// Check the length of an object.
+///
+/// Objects without a length, e.g. iterators and generators, can be handled
+/// with use_length_hint, count_items and assign_length_to_others, tried in
+/// this order.
+///
+/// Args:
+///     item (Sized): object whose length is checked
+///     expected_length (int): length to compare with
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message
+///     operator (callable or str): how to compare the length with
+///         expected_length. Either a callable, e.g. operator.ge, whose return
+///         value decides about the result, or one of "<", "<=", "==", "!=",
+///         ">=", ">" (or their names "lt", "le", "eq", "ne", "ge", "gt").
+///         Defaults to equality. Exceptions raised by a callable are
+///         propagated. Requires expected_length.
+///     assign_length_to_others (bool): treat objects without a length, e.g.
+///         numbers, as having length 1
+///     min_length (int): smallest accepted length (inclusive)
+///     max_length (int): largest accepted length (inclusive)
+///     use_length_hint (bool): use __length_hint__ for objects without
+///         __len__; the hint is an estimate and may differ from the actual
+///         number of items
+///     count_items (bool): count the items of iterables without __len__ by
+///         consuming them. Counting stops once the length is known to be
+///         too large, i.e. after max_length + 1 items (or expected_length + 1
+///         items when comparing for equality).
+///     return_items (bool): return the checked object, or a list of the
+///         consumed items if they were counted, so that the caller can still
+///         use them
+///     unit (str): how to measure the length of str and bytes objects:
+///         "bytes" (size in UTF-8), "chars" (code points, like len()) or
+///         "graphemes" (user-perceived characters, e.g. "e\u0301" is one)
+///
+/// At least one of expected_length, min_length and max_length is required.
+///
+/// Returns:
+///     None, if check succeeded.
+///     The item, or a list of the consumed items, if return_items is True.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     LengthError by default.
 #[pyfunction]
+#[pyo3(signature = (item, expected_length=None, handle_with=None, message=None, operator=None, assign_length_to_others=None, *, min_length=None, max_length=None, use_length_hint=None, count_items=None, return_items=None, unit=None))]
+#[allow(clippy::too_many_arguments)]
 pub fn check_length(_py: Python,
                     item: &PyAny,
-                    expected_length: &PyAny,
+                    expected_length: Option<&PyAny>,
                     handle_with: Option<&PyType>,
                     message: Option<&str>,
-                    operator: Option<PyObject>,
-                    assign_length_to_others: Option<bool>) -> PyResult<()> {
+                    operator: Option<&PyAny>,
+                    assign_length_to_others: Option<bool>,
+                    min_length: Option<usize>,
+                    max_length: Option<usize>,
+                    use_length_hint: Option<bool>,
+                    count_items: Option<bool>,
+                    return_items: Option<bool>,
+                    unit: Option<&str>) -> PyResult<Option<PyObject>> {
     let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
 
     if is_disabled {
-        return Ok(());
+        return Ok(None);
     }
 
-    match item.call_method0("__len__") {
-        Ok(result) => {
-            match operator {
-                None => {
-                    if result.eq(expected_length).unwrap_or(false) {
-                        Ok(())
-                    } else {
-                        raise_length_error_if(_py, handle_with, message)
-                    }
-                }
-                Some(op) => {
-                    if !op.getattr(_py, "__call__").is_ok() {
-                        let val: &PyAny = op.downcast(_py)?;
-                        Err(PyTypeError::new_err(format!("'{}' object is not callable", val.get_type().name().unwrap_or(""))))
-                    } else {
-                        match op.call_method(_py, "__call__", (result, expected_length), None) {
-                            Ok(_) => {Ok(())}
-                            Err(_) => {
-                                raise_length_error_if(_py, handle_with, message)
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    if expected_length.is_none() && min_length.is_none() && max_length.is_none() {
+        return Err(PyTypeError::new_err("check_length() missing 1 required positional argument: 'expected_length'"));
+    }
+
+    if operator.is_some() && expected_length.is_none() {
+        return Err(PyTypeError::new_err("check_length() operator requires expected_length"));
+    }
+
+    let return_items = return_items.unwrap_or(false);
+    let mut checked: PyObject = item.into_py(_py);
+
+    let measured = match unit {
+        None => { item.len() }
+        Some(unit) => { Ok(text_length(item, unit)?) }
+    };
+
+    let length = match measured {
+        Ok(length) => { length }
         Err(_) => {
-            if assign_length_to_others.unwrap_or(false) {
-                let pylist = PyList::new(_py, &[item]);
-                if pylist.call_method0("__len__").unwrap().eq(expected_length).unwrap_or(false) {
-                    Ok(())
-                } else {
-                    raise_length_error_if(_py, handle_with, message)
-                }
+            let hint: isize = if use_length_hint.unwrap_or(false) {
+                _py.import("operator")?.call_method1("length_hint", (item, -1))?.extract()?
+            } else {
+                -1
+            };
+
+            if hint >= 0 {
+                hint as usize
+            } else if count_items.unwrap_or(false) && item.iter().is_ok() {
+                let consumed = PyList::empty(_py);
+                let stop_at = count_limit(expected_length, operator, max_length);
+                let length = consume_and_count(item, stop_at, return_items.then_some(consumed))?;
+                checked = consumed.into_py(_py);
+                length
+            } else if assign_length_to_others.unwrap_or(false) {
+                PyList::new(_py, [item]).len()
             } else {
-                Err(PyTypeError::new_err(format!("'{}' has no len()", item.get_type().name().unwrap_or(""))))
+                return Err(PyTypeError::new_err(format!("'{}' has no len()", item.get_type().name().unwrap_or(""))));
             }
         }
+    };
+
+    if let Some(expected) = expected_length {
+        if !compare_length(_py, length, expected, operator)? {
+            return raise_length_error_if(_py, handle_with, message).map(|_| None);
+        }
     }
 
+    if min_length.is_some_and(|min| length < min) || max_length.is_some_and(|max| length > max) {
+        return raise_length_error_if(_py, handle_with, message).map(|_| None);
+    }
+
+    if return_items {
+        Ok(Some(checked))
+    } else {
+        Ok(None)
+    }
 }
 
 #[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (item, expected_length=None, handle_with=None, message=None, operator=None, assign_length_to_others=None, *, min_length=None, max_length=None, use_length_hint=None, count_items=None, return_items=None, unit=None))]
+#[allow(clippy::too_many_arguments)]
 pub fn assert_length(_py: Python,
                      item: &PyAny,
-                     expected_length: &PyAny,
+                     expected_length: Option<&PyAny>,
                      handle_with: Option<&PyType>,
                      message: Option<&str>,
-                     operator: Option<PyObject>,
-                     assign_length_to_others: Option<bool>) -> PyResult<()> {
-    check_length(_py, item, expected_length, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, operator, assign_length_to_others)
+                     operator: Option<&PyAny>,
+                     assign_length_to_others: Option<bool>,
+                     min_length: Option<usize>,
+                     max_length: Option<usize>,
+                     use_length_hint: Option<bool>,
+                     count_items: Option<bool>,
+                     return_items: Option<bool>,
+                    unit: Option<&str>) -> PyResult<Option<PyObject>> {
+    check_length(_py, item, expected_length, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, operator, assign_length_to_others, min_length, max_length, use_length_hint, count_items, return_items, unit)
+}
+
+/// Check that all elements of a nested structure have the same length.
+///
+/// Walks one level of nesting: the elements of a sequence, e.g. the rows of a
+/// list of lists, or the values of a mapping, e.g. the columns of a dict of
+/// lists. Without expected_length, the most common inner length is taken as
+/// the reference and all elements whose length differs are reported.
+///
+/// Args:
+///     items (Iterable[Sized] or Mapping[Any, Sized]): structure to check
+///     expected_length (int): length every element must have
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the offending indices (or
+///         keys) and their lengths.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     LengthError by default. The exception has an `offenders` attribute,
+///     a dict mapping offending indices (or keys) to their lengths.
+///
+/// >>> check_uniform_length([[1, 2], [3, 4], [5]])
+/// Traceback (most recent call last):
+///     ...
+/// LengthError: inner lengths differ from 2: [2] has length 1
+#[pyfunction]
+#[pyo3(signature = (items, /, expected_length=None, handle_with=None, message=None))]
+pub fn check_uniform_length(_py: Python,
+                            items: &PyAny,
+                            expected_length: Option<usize>,
+                            handle_with: Option<&PyType>,
+                            message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let abc_mod = _py.import("collections.abc")?;
+    let entries: Vec<(PyObject, &PyAny)> = if items.is_instance(abc_mod.getattr("Mapping")?)? {
+        items.call_method0("items")?
+            .iter()?
+            .map(|pair| pair.and_then(|pair| pair.extract::<(&PyAny, &PyAny)>()).map(|(key, value)| (key.into_py(_py), value)))
+            .collect::<PyResult<_>>()?
+    } else {
+        items.iter()?
+            .enumerate()
+            .map(|(index, value)| value.map(|value| (index.into_py(_py), value)))
+            .collect::<PyResult<_>>()?
+    };
+
+    let mut lengths = Vec::with_capacity(entries.len());
+    for (key, value) in &entries {
+        match value.len() {
+            Ok(length) => { lengths.push(length) }
+            Err(_) => {
+                return Err(PyTypeError::new_err(format!("element [{}] of type '{}' has no len()",
+                                                        key.as_ref(_py).repr()?,
+                                                        value.get_type().name().unwrap_or(""))));
+            }
+        }
+    }
+
+    let reference = match expected_length {
+        Some(length) => { length }
+        None => {
+            // the most common length, the first one seen in case of a tie
+            let mut counts: Vec<(usize, usize)> = Vec::new();
+            for length in &lengths {
+                match counts.iter_mut().find(|(seen, _)| seen == length) {
+                    Some((_, count)) => { *count += 1 }
+                    None => { counts.push((*length, 1)) }
+                }
+            }
+            match counts.iter().rev().max_by_key(|(_, count)| *count) {
+                Some((length, _)) => { *length }
+                None => { return Ok(()) }
+            }
+        }
+    };
+
+    let offenders = PyDict::new(_py);
+    let mut details = Vec::new();
+    for ((key, _), length) in entries.iter().zip(&lengths) {
+        if *length != reference {
+            offenders.set_item(key, length)?;
+            details.push(format!("[{}] has length {}", key.as_ref(_py).repr()?, length));
+        }
+    }
+
+    if details.is_empty() {
+        return Ok(());
+    }
+
+    let default_message = match expected_length {
+        Some(_) => { format!("expected inner length {}: {}", reference, details.join(", ")) }
+        None => { format!("inner lengths differ from {}: {}", reference, details.join(", ")) }
+    };
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+
+    match raise_length_error_if(_py, handle_with, Some(message)) {
+        Ok(()) => { Ok(()) }
+        Err(err) => { Err(helper::with_attributes(_py, err, vec![("offenders", offenders.into_py(_py))])) }
+    }
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (items, /, expected_length=None, handle_with=None, message=None))]
+pub fn assert_uniform_length(_py: Python,
+                             items: &PyAny,
+                             expected_length: Option<usize>,
+                             handle_with: Option<&PyType>,
+                             message: Option<&str>) -> PyResult<()> {
+    check_uniform_length(_py, items, expected_length, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
 }
@@ -2,9 +2,21 @@ mod if_condition;
 mod if_not_condition;
 mod helper;
 mod limit_condition;
+mod numeric_condition;
+mod datetime_condition;
 mod length_condition;
 mod is_close_condition;
 mod check_type_condition;
+mod check_class_condition;
+mod attribute_condition;
+mod callable_condition;
+mod enum_condition;
+mod membership_condition;
+mod collection_condition;
+mod predicate_condition;
+mod pattern_condition;
+mod format_condition;
+mod version_condition;
 mod path_condition;
 
 use pyo3::prelude::*;
@@ -18,16 +30,77 @@ fn reasycheck(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(if_not_condition::assert_if_not, m)?)?;
     m.add_function(wrap_pyfunction!(limit_condition::check_if_in_limits, m)?)?;
     m.add_function(wrap_pyfunction!(limit_condition::assert_if_in_limits, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_finite, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_finite, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_not_nan, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_not_nan, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_integer_valued, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_integer_valued, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_positive, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_positive, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_non_negative, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_non_negative, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_probability, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_probability, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_decimal_places, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_decimal_places, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::check_significant_digits, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_condition::assert_significant_digits, m)?)?;
+    m.add_function(wrap_pyfunction!(datetime_condition::check_datetime, m)?)?;
+    m.add_function(wrap_pyfunction!(datetime_condition::assert_datetime, m)?)?;
     m.add_function(wrap_pyfunction!(length_condition::check_length, m)?)?;
     m.add_function(wrap_pyfunction!(length_condition::assert_length, m)?)?;
+    m.add_function(wrap_pyfunction!(length_condition::check_uniform_length, m)?)?;
+    m.add_function(wrap_pyfunction!(length_condition::assert_uniform_length, m)?)?;
     m.add_function(wrap_pyfunction!(is_close_condition::check_if_isclose, m)?)?;
     m.add_function(wrap_pyfunction!(is_close_condition::assert_if_isclose, m)?)?;
     m.add_function(wrap_pyfunction!(check_type_condition::check_type, m)?)?;
     m.add_function(wrap_pyfunction!(check_type_condition::assert_type, m)?)?;
+    m.add_function(wrap_pyfunction!(check_class_condition::check_class, m)?)?;
+    m.add_function(wrap_pyfunction!(check_class_condition::assert_class, m)?)?;
+    m.add_function(wrap_pyfunction!(attribute_condition::check_has_attributes, m)?)?;
+    m.add_function(wrap_pyfunction!(attribute_condition::assert_has_attributes, m)?)?;
+    m.add_function(wrap_pyfunction!(callable_condition::check_callable, m)?)?;
+    m.add_function(wrap_pyfunction!(callable_condition::assert_callable, m)?)?;
+    m.add_function(wrap_pyfunction!(enum_condition::check_enum, m)?)?;
+    m.add_function(wrap_pyfunction!(enum_condition::assert_enum, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::check_in, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::assert_in, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::check_keys, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::assert_keys, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::check_subset, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::assert_subset, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::check_superset, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_condition::assert_superset, m)?)?;
+    m.add_function(wrap_pyfunction!(collection_condition::check_unique, m)?)?;
+    m.add_function(wrap_pyfunction!(collection_condition::assert_unique, m)?)?;
+    m.add_function(wrap_pyfunction!(collection_condition::check_sorted, m)?)?;
+    m.add_function(wrap_pyfunction!(collection_condition::assert_sorted, m)?)?;
+    m.add_function(wrap_pyfunction!(predicate_condition::check_all, m)?)?;
+    m.add_function(wrap_pyfunction!(predicate_condition::assert_all, m)?)?;
+    m.add_function(wrap_pyfunction!(predicate_condition::check_any, m)?)?;
+    m.add_function(wrap_pyfunction!(predicate_condition::assert_any, m)?)?;
+    m.add_function(wrap_pyfunction!(pattern_condition::check_matches, m)?)?;
+    m.add_function(wrap_pyfunction!(pattern_condition::assert_matches, m)?)?;
+    m.add_function(wrap_pyfunction!(format_condition::check_format, m)?)?;
+    m.add_function(wrap_pyfunction!(format_condition::assert_format, m)?)?;
+    m.add_function(wrap_pyfunction!(version_condition::check_version, m)?)?;
+    m.add_function(wrap_pyfunction!(version_condition::assert_version, m)?)?;
+    m.add_function(wrap_pyfunction!(version_condition::check_package_version, m)?)?;
+    m.add_function(wrap_pyfunction!(version_condition::assert_package_version, m)?)?;
     m.add_function(wrap_pyfunction!(path_condition::check_if_paths_exist, m)?)?;
     m.add_function(wrap_pyfunction!(path_condition::assert_paths, m)?)?;
     m.add("LimitError", _py.get_type::<limit_condition::LimitError>())?;
+    m.add("NotFiniteError", _py.get_type::<numeric_condition::NotFiniteError>())?;
+    m.add("NaNError", _py.get_type::<numeric_condition::NaNError>())?;
+    m.add("NotIntegerValuedError", _py.get_type::<numeric_condition::NotIntegerValuedError>())?;
+    m.add("NotPositiveError", _py.get_type::<numeric_condition::NotPositiveError>())?;
+    m.add("NegativeValueError", _py.get_type::<numeric_condition::NegativeValueError>())?;
+    m.add("NotProbabilityError", _py.get_type::<numeric_condition::NotProbabilityError>())?;
+    m.add("PrecisionError", _py.get_type::<numeric_condition::PrecisionError>())?;
     m.add("LengthError", _py.get_type::<length_condition::LengthError>())?;
     m.add("NotCloseEnoughError", _py.get_type::<is_close_condition::NotCloseEnoughError>())?;
+    m.add("SignatureError", _py.get_type::<callable_condition::SignatureError>())?;
+    m.add("VersionError", _py.get_type::<version_condition::VersionError>())?;
     Ok(())
 }
\ No newline at end of file
@@ -1,59 +1,336 @@
 use std::env;
+use std::fmt;
+use std::str::FromStr;
 
 use pyo3::prelude::*;
-use pyo3::exceptions::{PyAssertionError, PyBaseException};
-use pyo3::types::PyType;
+use pyo3::exceptions::{PyAssertionError, PyBaseException, PyValueError};
+use pyo3::types::{PyString, PyType};
 
 use crate::helper;
 
 pyo3::create_exception!(reasycheck, LimitError, PyBaseException);
 
+/// What a quantity written with a unit measures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Dimension {
+    /// counted in bytes
+    Size,
+    /// counted in seconds
+    Duration,
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dimension::Size => { write!(f, "size") }
+            Dimension::Duration => { write!(f, "duration") }
+        }
+    }
+}
+
+/// Units are case-sensitive: "m" is a minute, "MB" a megabyte and "Mb",
+/// which would be a megabit, is not accepted.
+const SIZE_UNITS: [(&str, f64); 14] = [
+    ("B", 1.0), ("byte", 1.0), ("bytes", 1.0),
+    ("kB", 1e3), ("KB", 1e3), ("MB", 1e6), ("GB", 1e9), ("TB", 1e12), ("PB", 1e15),
+    ("KiB", 1024.0), ("MiB", 1048576.0), ("GiB", 1073741824.0), ("TiB", 1099511627776.0), ("PiB", 1125899906842624.0),
+];
+
+/// Bare prefixes, which could mean a size as well as e.g. minutes.
+const AMBIGUOUS_UNITS: [&str; 6] = ["k", "K", "M", "G", "T", "P"];
+
+const DURATION_UNITS: [(&str, f64); 25] = [
+    ("ns", 1e-9), ("us", 1e-6), ("µs", 1e-6), ("ms", 1e-3),
+    ("s", 1.0), ("sec", 1.0), ("secs", 1.0), ("second", 1.0), ("seconds", 1.0),
+    ("m", 60.0), ("min", 60.0), ("mins", 60.0), ("minute", 60.0), ("minutes", 60.0),
+    ("h", 3600.0), ("hr", 3600.0), ("hrs", 3600.0), ("hour", 3600.0), ("hours", 3600.0),
+    ("d", 86400.0), ("day", 86400.0), ("days", 86400.0),
+    ("w", 604800.0), ("week", 604800.0), ("weeks", 604800.0),
+];
+
+/// A number, possibly written with a size or duration unit such as "512MiB",
+/// "10GB", "30s" or "1h 30m", converted to bytes or seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Quantity {
+    pub value: f64,
+    pub dimension: Option<Dimension>,
+}
+
+impl FromStr for Quantity {
+    type Err = String;
+
+    fn from_str(quantity: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid quantity '{}', expected a number with an optional unit, e.g. '512MiB', '10GB', '30s' or '2h'", quantity);
+        let text = quantity.trim();
+        if let Ok(value) = f64::from_str(text) {
+            return Ok(Quantity { value, dimension: None });
+        }
+
+        let mut value = 0.0;
+        let mut dimension = None;
+        let mut rest = text;
+        while !rest.is_empty() {
+            let number_end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).ok_or_else(invalid)?;
+            let number = f64::from_str(&rest[..number_end]).map_err(|_| invalid())?;
+            rest = rest[number_end..].trim_start();
+            let unit_end = rest.find(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace()).unwrap_or(rest.len());
+            let unit = &rest[..unit_end];
+            rest = rest[unit_end..].trim_start();
+            if AMBIGUOUS_UNITS.contains(&unit) {
+                return Err(format!("ambiguous unit '{}' in '{}', write e.g. '{}iB' or '{}B' for a size", unit, quantity, unit.to_uppercase(), unit));
+            }
+
+            let (factor, unit_dimension) = match SIZE_UNITS.iter().find(|(name, _)| *name == unit) {
+                Some((_, factor)) => { (*factor, Dimension::Size) }
+                None => {
+                    match DURATION_UNITS.iter().find(|(name, _)| *name == unit) {
+                        Some((_, factor)) => { (*factor, Dimension::Duration) }
+                        None => { return Err(invalid()) }
+                    }
+                }
+            };
+            if dimension.is_some_and(|dimension| dimension != unit_dimension) {
+                return Err(invalid());
+            }
+            dimension = Some(unit_dimension);
+            value += number * factor;
+        }
+        Ok(Quantity { value, dimension })
+    }
+}
+
+impl<'source> FromPyObject<'source> for Quantity {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        match ob.downcast::<PyString>() {
+            Ok(text) => { Quantity::from_str(text.to_str()?).map_err(PyValueError::new_err) }
+            Err(_) => { Ok(Quantity { value: ob.extract()?, dimension: None }) }
+        }
+    }
+}
+
+/// The common dimension of quantities; plain numbers go with any dimension.
+pub(crate) fn common_dimension(dimensions: &[Option<Dimension>]) -> PyResult<Option<Dimension>> {
+    let mut common = None;
+    for dimension in dimensions.iter().flatten() {
+        match common {
+            Some(found) if found != *dimension => {
+                return Err(PyValueError::new_err(format!("cannot compare a {} with a {}", found, dimension)));
+            }
+            _ => { common = Some(*dimension) }
+        }
+    }
+    Ok(common)
+}
+
+/// Write a number of bytes or seconds with the largest unit dividing it, e.g.
+/// "512MiB", "10GB" or "2h".
+pub(crate) fn format_quantity(value: f64, dimension: Option<Dimension>) -> String {
+    let units: &[(&str, f64)] = match dimension {
+        None => { return format!("{}", value) }
+        Some(Dimension::Size) => {
+            &[("PiB", 1125899906842624.0), ("PB", 1e15), ("TiB", 1099511627776.0), ("TB", 1e12), ("GiB", 1073741824.0),
+              ("GB", 1e9), ("MiB", 1048576.0), ("MB", 1e6), ("KiB", 1024.0), ("kB", 1e3)]
+        }
+        Some(Dimension::Duration) => { &[("w", 604800.0), ("d", 86400.0), ("h", 3600.0), ("m", 60.0)] }
+    };
+    if value.is_finite() && value != 0.0 {
+        if let Some((unit, factor)) = units.iter().find(|(_, factor)| (value / factor).fract() == 0.0) {
+            return format!("{}{}", value / factor, unit);
+        }
+    }
+    match dimension {
+        Some(Dimension::Size) if value.is_finite() => { format!("{}B", value) }
+        Some(Dimension::Duration) if value.is_finite() => { format!("{}s", value) }
+        _ => { format!("{}", value) }
+    }
+}
+
+/// An interval of the real line, written as e.g. "[0, 10)" or "(0, inf]".
+/// Bounds may be quantities such as "512MiB".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Interval {
+    pub lower: f64,
+    pub upper: f64,
+    pub include_lower: bool,
+    pub include_upper: bool,
+    pub dimension: Option<Dimension>,
+}
+
+impl Interval {
+    pub fn contains(&self, x: f64) -> bool {
+        let above = if self.include_lower { self.lower <= x } else { self.lower < x };
+        let below = if self.include_upper { x <= self.upper } else { x < self.upper };
+        above && below
+    }
+}
+
+fn parse_bound(bound: &str) -> Option<Quantity> {
+    match bound.trim().to_lowercase().as_str() {
+        "inf" | "+inf" | "infinity" | "+infinity" => { Some(Quantity { value: f64::INFINITY, dimension: None }) }
+        "-inf" | "-infinity" => { Some(Quantity { value: f64::NEG_INFINITY, dimension: None }) }
+        // f64::from_str would also accept "nan", which can't be a bound
+        _ => { Quantity::from_str(bound).ok().filter(|bound| !bound.value.is_nan()) }
+    }
+}
+
+impl FromStr for Interval {
+    type Err = String;
+
+    fn from_str(interval: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid interval '{}', expected e.g. '[0, 10)' or '(0, inf]'", interval);
+        let trimmed = interval.trim();
+        let include_lower = match trimmed.chars().next() {
+            Some('[') => { true }
+            Some('(') => { false }
+            _ => { return Err(invalid()) }
+        };
+        let include_upper = match trimmed.chars().last() {
+            Some(']') => { true }
+            Some(')') => { false }
+            _ => { return Err(invalid()) }
+        };
+        let (lower, upper) = trimmed[1..trimmed.len() - 1].split_once(',').ok_or_else(invalid)?;
+        let lower = parse_bound(lower).ok_or_else(invalid)?;
+        let upper = parse_bound(upper).ok_or_else(invalid)?;
+        let dimension = match (lower.dimension, upper.dimension) {
+            (Some(lower), Some(upper)) if lower != upper => {
+                return Err(format!("invalid interval '{}', cannot compare a {} with a {}", interval, lower, upper));
+            }
+            (lower, upper) => { lower.or(upper) }
+        };
+        if lower.value > upper.value {
+            return Err(format!("invalid interval '{}', the lower bound is greater than the upper one", interval));
+        }
+        Ok(Interval { lower: lower.value, upper: upper.value, include_lower, include_upper, dimension })
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}, {}{}",
+               if self.include_lower { '[' } else { '(' }, format_quantity(self.lower, self.dimension),
+               format_quantity(self.upper, self.dimension), if self.include_upper { ']' } else { ')' })
+    }
+}
+
+/// Check if a number lies within limits.
+///
+/// The limits are given either by lower_limit and upper_limit, or by an
+/// interval string. Which ends are included is controlled by include_equal
+/// for both of them, or by include_lower and include_upper separately.
+///
+/// The limits may also be sizes or durations written with a unit, e.g.
+/// "512MiB", "10GB", "30s" or "1h 30m", which are compared in bytes or
+/// seconds; x is then taken to be in bytes or seconds as well. Units are
+/// case-sensitive, and a bare prefix such as "512M" is rejected as
+/// ambiguous.
+///
+/// Args:
+///     x (float): number to check
+///     lower_limit (float or str): lower limit, None means no limit
+///     upper_limit (float or str): upper limit, None means no limit
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming x and the interval.
+///     include_equal (bool): whether both limits belong to the interval.
+///         Defaults to True.
+///     include_lower (bool): whether lower_limit belongs to the interval,
+///         overrides include_equal
+///     include_upper (bool): whether upper_limit belongs to the interval,
+///         overrides include_equal
+///     interval (str): the interval in mathematical notation, e.g.
+///         "[0, 10)" or "(0, inf]", instead of the other arguments
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     LimitError by default.
+///
+/// >>> check_if_in_limits(1, interval="[0, 1)")
+/// Traceback (most recent call last):
+///     ...
+/// LimitError: x = 1 is not within [0, 1)
+/// >>> check_if_in_limits(600 * 2**20, upper_limit="512MiB")
+/// Traceback (most recent call last):
+///     ...
+/// LimitError: x = 600MiB is not within [-inf, 512MiB]
 #[pyfunction]
+#[pyo3(signature = (x, lower_limit=None, upper_limit=None, handle_with=None, message=None, include_equal=None, *, include_lower=None, include_upper=None, interval=None))]
+#[allow(clippy::too_many_arguments)]
 pub fn check_if_in_limits(_py: Python,
                           x: f64,
-                          lower_limit: Option<f64>,
-                          upper_limit: Option<f64>,
+                          lower_limit: Option<Quantity>,
+                          upper_limit: Option<Quantity>,
                           handle_with: Option<&PyType>,
                           message: Option<&str>,
-                          include_equal: Option<bool>) -> PyResult<()> {
+                          include_equal: Option<bool>,
+                          include_lower: Option<bool>,
+                          include_upper: Option<bool>,
+                          interval: Option<&str>) -> PyResult<()> {
     let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
 
     if is_disabled {
         return Ok(());
     }
 
-    let mut throw_error = true;
-    let llimit = lower_limit.unwrap_or(f64::NEG_INFINITY);
-    let ulimit = upper_limit.unwrap_or(f64::INFINITY);
-
-    if include_equal.unwrap_or(true) {
-        throw_error = !((llimit <= x) && (x <= ulimit));
-    } else {
-        throw_error = !((llimit < x) && (x < ulimit));
-    }
-
-    if throw_error {
-        match handle_with {
-            None => {
-                Err(helper::check_handle_with(Some(_py.get_type::<LimitError>()), message))
+    let interval = match interval {
+        Some(interval) => {
+            if lower_limit.is_some() || upper_limit.is_some() || include_equal.is_some() || include_lower.is_some() || include_upper.is_some() {
+                return Err(PyValueError::new_err("interval cannot be combined with limits or include_* arguments"));
             }
-            Some(_) => {
-                Err(helper::check_handle_with(handle_with, message))
+            Interval::from_str(interval).map_err(PyValueError::new_err)?
+        }
+        None => {
+            let include_equal = include_equal.unwrap_or(true);
+            Interval {
+                lower: lower_limit.map_or(f64::NEG_INFINITY, |limit| limit.value),
+                upper: upper_limit.map_or(f64::INFINITY, |limit| limit.value),
+                include_lower: include_lower.unwrap_or(include_equal),
+                include_upper: include_upper.unwrap_or(include_equal),
+                dimension: common_dimension(&[lower_limit.and_then(|limit| limit.dimension), upper_limit.and_then(|limit| limit.dimension)])?,
             }
         }
+    };
+    let dimension = interval.dimension;
+
+    if interval.contains(x) {
+        return Ok(());
+    }
 
+    // NaN compares false with everything, so say why the check failed
+    let default_message = if x.is_nan() {
+        format!("x is NaN, which is never within {}", interval)
+    } else if interval.lower.is_nan() || interval.upper.is_nan() {
+        format!("a limit is NaN, so no value is within {}", interval)
     } else {
-        Ok(())
+        format!("x = {} is not within {}", format_quantity(x, dimension), interval)
+    };
+    let message = message.or(Some(default_message.as_str()));
+    match handle_with {
+        None => {
+            Err(helper::check_handle_with(Some(_py.get_type::<LimitError>()), message))
+        }
+        Some(_) => {
+            Err(helper::check_handle_with(handle_with, message))
+        }
     }
 }
 
 #[pyfunction]
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (x, lower_limit=None, upper_limit=None, handle_with=None, message=None, include_equal=None, *, include_lower=None, include_upper=None, interval=None))]
 pub fn assert_if_in_limits(_py: Python,
                            x: f64,
-                           lower_limit: Option<f64>,
-                           upper_limit: Option<f64>,
+                           lower_limit: Option<Quantity>,
+                           upper_limit: Option<Quantity>,
                            handle_with: Option<&PyType>,
                            message: Option<&str>,
-                           include_equal: Option<bool>) -> PyResult<()> {
-    check_if_in_limits(_py, x, lower_limit, upper_limit, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, include_equal)
-}
\ No newline at end of file
+                           include_equal: Option<bool>,
+                           include_lower: Option<bool>,
+                           include_upper: Option<bool>,
+                           interval: Option<&str>) -> PyResult<()> {
+    check_if_in_limits(_py, x, lower_limit, upper_limit, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, include_equal, include_lower, include_upper, interval)
+}
@@ -0,0 +1,326 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyValueError};
+use pyo3::types::{PyList, PySet, PyString, PyType};
+
+use crate::helper;
+
+/// Check that a value is one of the given choices.
+///
+/// If the value is a string and not among the choices, the most similar
+/// choice (by edit distance, ignoring case) is suggested in the message.
+///
+/// Args:
+///     value (Any): value to check
+///     choices (Iterable): the valid values
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the choices and a suggestion.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. The exception has a `suggestion` attribute,
+///     the suggested choice or None.
+///
+/// >>> check_in("prodution", ["development", "production"])
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: 'prodution' is not one of ['development', 'production']; did you mean 'production'?
+#[pyfunction]
+#[pyo3(signature = (value, choices, /, handle_with=None, message=None))]
+pub fn check_in(_py: Python,
+                value: &PyAny,
+                choices: &PyAny,
+                handle_with: Option<&PyType>,
+                message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    // materialise the choices, so that iterators can be both searched and listed
+    let choices = PyList::new(_py, choices.iter()?.collect::<PyResult<Vec<_>>>()?);
+    if choices.contains(value)? {
+        return Ok(());
+    }
+
+    let mut suggestion: Option<&PyAny> = None;
+    if let Ok(target) = value.downcast::<PyString>() {
+        let candidates: Vec<(&str, &PyAny)> = choices.iter()
+            .filter_map(|choice| choice.downcast::<PyString>().ok().and_then(|name| name.to_str().ok()).map(|name| (name, choice)))
+            .collect();
+        if let Some(closest) = helper::closest_match(target.to_str()?, candidates.iter().map(|(name, _)| *name)) {
+            suggestion = candidates.iter().find(|(name, _)| *name == closest).map(|(_, choice)| *choice);
+        }
+    }
+
+    let mut default_message = format!("{} is not one of {}", value.repr()?, choices.repr()?);
+    if let Some(suggested) = suggestion {
+        default_message.push_str(&format!("; did you mean {}?", suggested.repr()?));
+    }
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyValueError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("suggestion", suggestion.into_py(_py))]))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (value, choices, /, handle_with=None, message=None))]
+pub fn assert_in(_py: Python,
+                 value: &PyAny,
+                 choices: &PyAny,
+                 handle_with: Option<&PyType>,
+                 message: Option<&str>) -> PyResult<()> {
+    check_in(_py, value, choices, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Elements of `items` which are not in `reference`, without duplicates and
+/// in the order of `items`.
+fn elements_not_in<'py>(_py: Python<'py>, items: &'py PyAny, reference: &PySet) -> PyResult<Vec<&'py PyAny>> {
+    let seen = PySet::empty(_py)?;
+    let mut found = Vec::new();
+
+    for element in items.iter()? {
+        let element = element?;
+        if !reference.contains(element)? && !seen.contains(element)? {
+            seen.add(element)?;
+            found.push(element);
+        }
+    }
+    Ok(found)
+}
+
+fn join_reprs(elements: &[&PyAny]) -> PyResult<String> {
+    Ok(elements.iter().map(|element| element.repr().map(|repr| repr.to_string())).collect::<PyResult<Vec<_>>>()?.join(", "))
+}
+
+/// Raise the error of a failed collection check, carrying the missing and
+/// unexpected elements.
+fn raise_collection_error(_py: Python,
+                          handle_with: Option<&PyType>,
+                          message: Option<&str>,
+                          default_message: String,
+                          missing: Vec<&PyAny>,
+                          unexpected: Vec<&PyAny>) -> PyResult<()> {
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyValueError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("missing", missing.into_py(_py)), ("unexpected", unexpected.into_py(_py))]))
+}
+
+/// Check the keys of a mapping.
+///
+/// Args:
+///     mapping (Mapping): mapping whose keys are checked, e.g. a loaded
+///         config
+///     required (Iterable): keys which must be present
+///     optional (Iterable): keys which may be present
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the missing and the
+///         unexpected keys.
+///     allow_extra (bool): accept keys which are neither required nor
+///         optional. Defaults to True.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. The exception has a `missing` and an
+///     `unexpected` attribute, lists of the offending keys.
+///
+/// >>> check_keys({"host": "x", "prt": 1}, required=["host", "port"], allow_extra=False)
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: missing keys: 'port'; unexpected keys: 'prt' (did you mean 'port'?)
+#[pyfunction]
+#[pyo3(signature = (mapping, /, required=None, optional=None, handle_with=None, message=None, *, allow_extra=true))]
+pub fn check_keys(_py: Python,
+                  mapping: &PyAny,
+                  required: Option<&PyAny>,
+                  optional: Option<&PyAny>,
+                  handle_with: Option<&PyType>,
+                  message: Option<&str>,
+                  allow_extra: bool) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let keys = PySet::new(_py, mapping.call_method0("keys")?.iter()?.collect::<PyResult<Vec<_>>>()?)?;
+    let missing = match required {
+        None => { Vec::new() }
+        Some(required) => { elements_not_in(_py, required, keys)? }
+    };
+
+    let mut unexpected = Vec::new();
+    let mut allowed_names: Vec<String> = Vec::new();
+    if !allow_extra {
+        let allowed = PySet::empty(_py)?;
+        for known in [required, optional].into_iter().flatten() {
+            for key in known.iter()? {
+                let key = key?;
+                allowed.add(key)?;
+                if let Ok(name) = key.extract::<String>() {
+                    allowed_names.push(name);
+                }
+            }
+        }
+        unexpected = elements_not_in(_py, mapping.call_method0("keys")?, allowed)?;
+    }
+
+    if missing.is_empty() && unexpected.is_empty() {
+        return Ok(());
+    }
+
+    let mut problems = Vec::new();
+    if !missing.is_empty() {
+        problems.push(format!("missing keys: {}", join_reprs(&missing)?));
+    }
+    if !unexpected.is_empty() {
+        let mut described = Vec::new();
+        for key in &unexpected {
+            let suggestion = key.extract::<&str>().ok()
+                .and_then(|name| helper::closest_match(name, allowed_names.iter().map(String::as_str)));
+            match suggestion {
+                None => { described.push(key.repr()?.to_string()) }
+                Some(suggested) => { described.push(format!("{} (did you mean '{}'?)", key.repr()?, suggested)) }
+            }
+        }
+        problems.push(format!("unexpected keys: {}", described.join(", ")));
+    }
+
+    raise_collection_error(_py, handle_with, message, problems.join("; "), missing, unexpected)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (mapping, /, required=None, optional=None, handle_with=None, message=None, *, allow_extra=true))]
+pub fn assert_keys(_py: Python,
+                   mapping: &PyAny,
+                   required: Option<&PyAny>,
+                   optional: Option<&PyAny>,
+                   handle_with: Option<&PyType>,
+                   message: Option<&str>,
+                   allow_extra: bool) -> PyResult<()> {
+    check_keys(_py, mapping, required, optional, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, allow_extra)
+}
+
+/// Check that all elements of an iterable are among the allowed ones.
+///
+/// Args:
+///     items (Iterable): elements to check, e.g. tags
+///     allowed (Iterable): the allowed elements
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the unexpected elements.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. The exception has a `missing` (always empty)
+///     and an `unexpected` attribute, a list of the elements not allowed.
+#[pyfunction]
+#[pyo3(signature = (items, allowed, /, handle_with=None, message=None))]
+pub fn check_subset(_py: Python,
+                    items: &PyAny,
+                    allowed: &PyAny,
+                    handle_with: Option<&PyType>,
+                    message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let allowed = PySet::new(_py, allowed.iter()?.collect::<PyResult<Vec<_>>>()?)?;
+    let unexpected = elements_not_in(_py, items, allowed)?;
+
+    if unexpected.is_empty() {
+        return Ok(());
+    }
+
+    let default_message = format!("unexpected elements: {}", join_reprs(&unexpected)?);
+    raise_collection_error(_py, handle_with, message, default_message, Vec::new(), unexpected)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (items, allowed, /, handle_with=None, message=None))]
+pub fn assert_subset(_py: Python,
+                     items: &PyAny,
+                     allowed: &PyAny,
+                     handle_with: Option<&PyType>,
+                     message: Option<&str>) -> PyResult<()> {
+    check_subset(_py, items, allowed, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that an iterable contains all the required elements.
+///
+/// Args:
+///     items (Iterable): elements to check
+///     required (Iterable): elements which must be among items
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the missing elements.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. The exception has a `missing` attribute, a
+///     list of the missing elements, and an (always empty) `unexpected` one.
+#[pyfunction]
+#[pyo3(signature = (items, required, /, handle_with=None, message=None))]
+pub fn check_superset(_py: Python,
+                      items: &PyAny,
+                      required: &PyAny,
+                      handle_with: Option<&PyType>,
+                      message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let items = PySet::new(_py, items.iter()?.collect::<PyResult<Vec<_>>>()?)?;
+    let missing = elements_not_in(_py, required, items)?;
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let default_message = format!("missing elements: {}", join_reprs(&missing)?);
+    raise_collection_error(_py, handle_with, message, default_message, missing, Vec::new())
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (items, required, /, handle_with=None, message=None))]
+pub fn assert_superset(_py: Python,
+                       items: &PyAny,
+                       required: &PyAny,
+                       handle_with: Option<&PyType>,
+                       message: Option<&str>) -> PyResult<()> {
+    check_superset(_py, items, required, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
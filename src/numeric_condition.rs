@@ -0,0 +1,363 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::buffer::{Element, PyBuffer};
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyValueError};
+use pyo3::types::{PyBool, PyFloat, PyLong, PyString, PyType};
+
+use crate::helper;
+use crate::limit_condition::LimitError;
+
+pyo3::create_exception!(reasycheck, NotFiniteError, LimitError);
+pyo3::create_exception!(reasycheck, NaNError, LimitError);
+pyo3::create_exception!(reasycheck, NotIntegerValuedError, LimitError);
+pyo3::create_exception!(reasycheck, NotPositiveError, LimitError);
+pyo3::create_exception!(reasycheck, NegativeValueError, LimitError);
+pyo3::create_exception!(reasycheck, NotProbabilityError, LimitError);
+pyo3::create_exception!(reasycheck, PrecisionError, LimitError);
+
+/// The values of a numeric buffer of `T`, or None if `x` does not expose such
+/// a buffer.
+fn buffer_values<T: Element + Copy>(_py: Python, x: &PyAny, convert: fn(T) -> f64) -> PyResult<Option<Vec<f64>>> {
+    match PyBuffer::<T>::get(x) {
+        Ok(buffer) => { Ok(Some(buffer.to_vec(_py)?.into_iter().map(convert).collect())) }
+        Err(_) => { Ok(None) }
+    }
+}
+
+fn array_values(_py: Python, x: &PyAny) -> PyResult<Option<Vec<f64>>> {
+    if let Some(values) = buffer_values::<f64>(_py, x, |v| v)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<f32>(_py, x, f64::from)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<i64>(_py, x, |v| v as f64)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<i32>(_py, x, f64::from)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<i16>(_py, x, f64::from)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<i8>(_py, x, f64::from)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<u64>(_py, x, |v| v as f64)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<u32>(_py, x, f64::from)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<u16>(_py, x, f64::from)? { return Ok(Some(values)) }
+    if let Some(values) = buffer_values::<u8>(_py, x, f64::from)? { return Ok(Some(values)) }
+    // the data pointer of an empty buffer may not be aligned for its type
+    match _py.import("builtins")?.getattr("memoryview")?.call1((x,)) {
+        Ok(view) if view.getattr("nbytes")?.extract::<usize>()? == 0 => { Ok(Some(Vec::new())) }
+        _ => { Ok(None) }
+    }
+}
+
+/// Check that `accept` holds for a number, or for every value of a numeric
+/// buffer, raising `error` for the first offending one.
+fn check_values(_py: Python,
+                x: &PyAny,
+                accept: fn(f64) -> bool,
+                expected: &str,
+                error: &PyType,
+                handle_with: Option<&PyType>,
+                message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let number = match x.extract::<f64>() {
+        // an int too large for a float is finite and integer-valued; stand
+        // in the largest float of the same sign for it
+        Err(_) if x.is_instance_of::<PyLong>() => { Ok(if x.gt(0)? { f64::MAX } else { f64::MIN }) }
+        number => { number }
+    };
+
+    let (default_message, attributes) = match number {
+        Ok(value) => {
+            if accept(value) {
+                return Ok(());
+            }
+            (format!("value must be {}, got {}", expected, x.repr()?), vec![("value", x.into_py(_py))])
+        }
+        Err(err) => {
+            let values = match array_values(_py, x)? {
+                Some(values) => { values }
+                None => { return Err(err) }
+            };
+            let idx = match values.iter().position(|value| !accept(*value)) {
+                Some(idx) => { idx }
+                None => { return Ok(()) }
+            };
+            let value = values[idx].into_py(_py);
+            (format!("values must be {}, got {} at [{}]", expected, value.as_ref(_py).repr()?, idx),
+             vec![("value", value), ("index", idx.into_py(_py))])
+        }
+    };
+
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { helper::check_handle_warning_with(Some(error), Some(message)) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, attributes))
+}
+
+/// Check that a number, or every value of a numeric array, is finite, i.e.
+/// neither infinite nor NaN.
+///
+/// Args:
+///     x (float or buffer): number, or an object supporting the buffer
+///         protocol, e.g. array.array or a numpy array
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming the offending value.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     NotFiniteError (a LimitError) by default. The exception has a `value`
+///     attribute, the first offending value, and for arrays an `index` one.
+///
+/// >>> check_finite(array.array("d", [1.0, float("inf")]))
+/// Traceback (most recent call last):
+///     ...
+/// NotFiniteError: values must be finite, got inf at [1]
+#[pyfunction]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn check_finite(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_values(_py, x, f64::is_finite, "finite", _py.get_type::<NotFiniteError>(), handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn assert_finite(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_finite(_py, x, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that a number, or every value of a numeric array, is not NaN.
+///
+/// Arguments and return value as in check_finite; raises NaNError (a
+/// LimitError) by default.
+#[pyfunction]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn check_not_nan(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_values(_py, x, |value| !value.is_nan(), "not NaN", _py.get_type::<NaNError>(), handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn assert_not_nan(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_not_nan(_py, x, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that a number, or every value of a numeric array, is a whole
+/// number, e.g. 3 or 3.0 but not 3.5 or inf.
+///
+/// Arguments and return value as in check_finite; raises
+/// NotIntegerValuedError (a LimitError) by default.
+#[pyfunction]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn check_integer_valued(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_values(_py, x, |value| value.is_finite() && value.fract() == 0.0, "integer-valued", _py.get_type::<NotIntegerValuedError>(), handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn assert_integer_valued(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_integer_valued(_py, x, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that a number, or every value of a numeric array, is greater than 0.
+///
+/// Arguments and return value as in check_finite; raises NotPositiveError (a
+/// LimitError) by default.
+#[pyfunction]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn check_positive(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_values(_py, x, |value| value > 0.0, "positive", _py.get_type::<NotPositiveError>(), handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn assert_positive(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_positive(_py, x, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that a number, or every value of a numeric array, is 0 or greater.
+///
+/// Arguments and return value as in check_finite; raises NegativeValueError
+/// (a LimitError) by default.
+#[pyfunction]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn check_non_negative(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_values(_py, x, |value| value >= 0.0, "non-negative", _py.get_type::<NegativeValueError>(), handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn assert_non_negative(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_non_negative(_py, x, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that a number, or every value of a numeric array, is a probability,
+/// i.e. between 0 and 1 inclusive.
+///
+/// Arguments and return value as in check_finite; raises NotProbabilityError
+/// (a LimitError) by default.
+#[pyfunction]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn check_probability(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_values(_py, x, |value| (0.0..=1.0).contains(&value), "between 0 and 1", _py.get_type::<NotProbabilityError>(), handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (x, /, handle_with=None, message=None))]
+pub fn assert_probability(_py: Python, x: &PyAny, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_probability(_py, x, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// The significant digits of a number written in decimal notation, without
+/// leading and trailing zeros, and the exponent of the last of them, e.g.
+/// ("12", -3) for "0.01200" or ("12", 2) for "1.2e3". Zero has no digits.
+fn decimal_digits(text: &str) -> Option<(String, i64)> {
+    let text = text.trim().trim_start_matches(['+', '-']);
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => { (mantissa, exponent.parse::<i64>().ok()?) }
+        None => { (text, 0) }
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits = format!("{}{}", integer, fraction);
+    let significant = digits.trim_start_matches('0');
+    let trimmed = significant.trim_end_matches('0');
+    if trimmed.is_empty() {
+        return Some((String::new(), 0));
+    }
+    let exponent = exponent - fraction.len() as i64 + (significant.len() - trimmed.len()) as i64;
+    Some((trimmed.to_string(), exponent))
+}
+
+/// Digits of a Decimal, str, int or float. Floats are read from their
+/// shortest representation, so 0.1 has one decimal place.
+fn value_digits(_py: Python, value: &PyAny) -> PyResult<(String, i64)> {
+    let decimal = _py.import("decimal")?.getattr("Decimal")?;
+    // bools are ints, but have no decimal places to speak of
+    if value.is_instance_of::<PyBool>() {
+        return Err(PyTypeError::new_err("expected Decimal, str, int or float, got 'bool'"));
+    }
+    let text = if value.is_instance_of::<PyString>() || value.is_instance(decimal)? || value.is_instance_of::<PyLong>() {
+        value.str()?.to_string()
+    } else if value.is_instance_of::<PyFloat>() {
+        format!("{}", value.extract::<f64>()?)
+    } else {
+        return Err(PyTypeError::new_err(format!("expected Decimal, str, int or float, got '{}'", value.get_type().name()?)));
+    };
+    decimal_digits(&text).ok_or_else(|| PyValueError::new_err(format!("{} is not a finite decimal number", value.repr().map(|repr| repr.to_string()).unwrap_or(text))))
+}
+
+fn check_precision(_py: Python,
+                   value: &PyAny,
+                   precision: fn(&(String, i64)) -> usize,
+                   maximum: usize,
+                   what: &str,
+                   handle_with: Option<&PyType>,
+                   message: Option<&str>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let actual = precision(&value_digits(_py, value)?);
+    if actual <= maximum {
+        return Ok(());
+    }
+
+    let default_message = format!("{} has {} {}, at most {} allowed", value.repr()?, actual, what, maximum);
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { helper::check_handle_warning_with(Some(_py.get_type::<PrecisionError>()), Some(message)) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("precision", actual.into_py(_py))]))
+}
+
+/// Check that a number has at most the given number of decimal places.
+///
+/// Decimal, str and int values are checked exactly; a float is checked by its
+/// shortest representation, e.g. 0.1 has 1 decimal place even though the
+/// binary value is not exactly 0.1. Trailing zeros don't count, so
+/// Decimal("1.50") has 1 decimal place.
+///
+/// Args:
+///     value (Decimal, str, int or float): number to check
+///     max_places (int): maximum number of decimal places
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming the actual precision.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     PrecisionError (a LimitError) by default. The exception has a
+///     `precision` attribute, the actual number of decimal places.
+///
+/// >>> check_decimal_places(Decimal("19.999"), 2)
+/// Traceback (most recent call last):
+///     ...
+/// PrecisionError: Decimal('19.999') has 3 decimal places, at most 2 allowed
+#[pyfunction]
+#[pyo3(signature = (value, max_places, /, handle_with=None, message=None))]
+pub fn check_decimal_places(_py: Python, value: &PyAny, max_places: usize, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_precision(_py, value, |(_, exponent)| (-exponent).max(0) as usize, max_places, "decimal places", handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (value, max_places, /, handle_with=None, message=None))]
+pub fn assert_decimal_places(_py: Python, value: &PyAny, max_places: usize, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_decimal_places(_py, value, max_places, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
+
+/// Check that a number has at most the given number of significant digits.
+///
+/// Values are read as in check_decimal_places. Leading and trailing zeros
+/// don't count, so both 0.00120 and 1200 have 2 significant digits.
+///
+/// Args:
+///     value (Decimal, str, int or float): number to check
+///     max_digits (int): maximum number of significant digits
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming the actual precision.
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     PrecisionError (a LimitError) by default. The exception has a
+///     `precision` attribute, the actual number of significant digits.
+#[pyfunction]
+#[pyo3(signature = (value, max_digits, /, handle_with=None, message=None))]
+pub fn check_significant_digits(_py: Python, value: &PyAny, max_digits: usize, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_precision(_py, value, |(digits, _)| digits.len(), max_digits, "significant digits", handle_with, message)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (value, max_digits, /, handle_with=None, message=None))]
+pub fn assert_significant_digits(_py: Python, value: &PyAny, max_digits: usize, handle_with: Option<&PyType>, message: Option<&str>) -> PyResult<()> {
+    check_significant_digits(_py, value, max_digits, Option::from(PyAssertionError::new_err(()).get_type(_py)), message)
+}
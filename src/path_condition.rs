@@ -1,13 +1,9 @@
-use panic::catch_unwind;
-use std::any::Any;
-use std::{env, panic};
-use std::fmt::Debug;
-use std::ops::Deref;
-use std::path::Path;
+use std::{env, fs};
+use std::path::{Path, PathBuf};
 
-use pyo3::{AsPyPointer, FromPyPointer, Py, PyAny, PyErr, pyfunction, PyObject, PyResult, Python, PyTypeInfo, ToPyObject};
+use pyo3::{AsPyPointer, Py, PyAny, PyErr, pyfunction, PyObject, Python, PyTypeInfo, ToPyObject};
 use pyo3::exceptions::{PyAssertionError, PyFileNotFoundError, PyRuntimeError, PyTypeError, PyValueError};
-use pyo3::ffi::{PyGen_New, PyIter_Next, PyObject_IsInstance};
+use pyo3::ffi::{PyIter_Next, PyObject_IsInstance};
 use pyo3::types::{PyList, PyModule, PyString, PyTuple, PyType};
 use crate::helper;
 
@@ -21,9 +17,13 @@ use crate::helper;
 ///         to validate
 ///     handle_with (type): type of exception or warning to be raised/returned
 ///     message (str): a text to use as the exception/warning message.
-///         Defaults to None, which means using no message for built-in
-///         exceptions/warnings, and the docstrings of the exception/warning
-///         class as a message for custom exceptions.
+///         Defaults to "", which means that the default FileNotFoundError
+///         names the path and suggests a similarly named entry of its parent
+///         directory, if there is one, e.g. "data/inputs.csv is not a valid
+///         path; did you mean data/input.csv?". Other exceptions/warnings
+///         get no message if built-in, and the docstring of their class if
+///         custom. With execution_mode 'return', the returned
+///         FileNotFoundError has no message and no suggestion.
 ///     execution_mode (str): defines what happens if not all the paths exist
 ///         May take one of the following values:
 ///             - 'raise': exception/warning will be raised
@@ -43,13 +43,13 @@ use crate::helper;
 /// >>> check_if_paths_exist('Q:/Op/Oop/')
 /// Traceback (most recent call last):
 ///     ...
-/// FileNotFoundError
+/// FileNotFoundError: Q:/Op/Oop/ is not a valid path
 /// >>> check_if_paths_exist(os.listdir()[0])
 /// >>> check_if_paths_exist(Path(os.listdir()[0]))
 /// >>> check_if_paths_exist(os.listdir())
 ///
 /// >>> check_if_paths_exist('Q:/Op/Oop', execution_mode='return')
-/// (FileNotFoundError(), ['Q:/Op/Oop'])
+/// (FileNotFoundError(''), ['Q:/Op/Oop'])
 /// >>> check_if_paths_exist(os.listdir()[0], execution_mode='return')
 /// (None, [])
 /// >>> check_if_paths_exist(os.listdir(), execution_mode='return')
@@ -82,12 +82,10 @@ pub unsafe fn check_if_paths_exist(_py: Python,
     }
 
     match execution_mode {
-        None => {}
-        Some(mode) => {
-            if mode != "raise" && mode != "return" {
-                return Err(PyValueError::new_err("execution_mode can only be `raise` or `return`"));
-            }
+        Some(mode) if mode != "raise" && mode != "return" => {
+            return Err(PyValueError::new_err("execution_mode can only be `raise` or `return`"));
         }
+        _ => {}
     }
 
     if PyObject_IsInstance(paths.as_ptr(), PyString::type_object(_py).as_ptr()).is_positive() {
@@ -137,7 +135,7 @@ pub unsafe fn check_if_paths_exist(_py: Python,
                            py_iterable_obj.as_ptr()).is_positive() {
         let ex_mode = execution_mode.unwrap();
 
-        while true {
+        loop {
             let mut_obj = PyIter_Next(paths.as_ptr());
 
             match mut_obj.as_ref() {
@@ -171,25 +169,41 @@ pub unsafe fn check_if_paths_exist(_py: Python,
         return return_success(_py, ex_mode);
     }
 
-    return Err(PyTypeError::new_err("Argument paths must be string"));
+    Err(PyTypeError::new_err("Argument paths must be string"))
 }
 
 fn return_success(_py: Python, ex_mode: &str) -> Result<Option<Py<PyAny>>, PyErr> {
     match ex_mode {
         "raise" => {
-            return Ok(None);
+            Ok(None)
         }
         "return" => {
             let empty_list: pyo3::PyObject = PyList::empty(_py).to_object(_py);
             let elements = vec![None, Some(empty_list)];
             let result: &PyAny = PyTuple::new(_py, elements);
 
-            return Ok(Some(Py::from(result)));
+            Ok(Some(Py::from(result)))
         }
-        _ => { return Err(PyRuntimeError::new_err("Something went wrong.")); }
+        _ => { Err(PyRuntimeError::new_err("Something went wrong.")) }
     }
 }
 
+/// A similarly named entry in the parent directory of a non-existing path,
+/// e.g. `data/input.csv` for `data/inputs.csv`.
+fn suggest_sibling(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => { parent }
+        _ => { Path::new(".") }
+    };
+    let siblings: Vec<String> = fs::read_dir(parent).ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .collect();
+
+    helper::closest_match(name, siblings.iter().map(String::as_str))
+        .map(|sibling| path.with_file_name(sibling))
+}
+
 fn check_single_path(_py: Python,
                      paths: PyObject,
                      handle_with: Option<&PyType>,
@@ -200,35 +214,38 @@ fn check_single_path(_py: Python,
     if Path::new(paths.to_string().as_str()).exists() {
         match ex_mode {
             "raise" => {
-                return Ok(None);
+                Ok(None)
             }
             "return" => {
                 let empty_list: pyo3::PyObject = PyList::empty(_py).to_object(_py);
                 let elements = vec![None, Some(empty_list)];
                 let result: &PyAny = PyTuple::new(_py, elements);
 
-                return Ok(Some(Py::from(result)));
+                Ok(Some(Py::from(result)))
             }
-            _ => { return Err(PyRuntimeError::new_err("Something went wrong.")); }
+            _ => { Err(PyRuntimeError::new_err("Something went wrong.")) }
         }
     } else {
         match ex_mode {
             "raise" => {
                 match handle_with {
                     None => {
-                        let mut err_msg: String = "".to_string();
-                        match message {
-                            None => {
-                                err_msg = format!("{} is not a valid path", paths.to_string().as_str());
+                        let err_msg = match message {
+                            None | Some("") => {
+                                let path = paths.to_string();
+                                match suggest_sibling(Path::new(path.as_str())) {
+                                    None => { format!("{} is not a valid path", path) }
+                                    Some(sibling) => { format!("{} is not a valid path; did you mean {}?", path, sibling.display()) }
+                                }
                             }
                             Some(msg) => {
-                                err_msg = msg.to_string();
+                                msg.to_string()
                             }
-                        }
-                        return Err(PyFileNotFoundError::new_err(err_msg));
+                        };
+                        Err(PyFileNotFoundError::new_err(err_msg))
                     }
                     Some(_) => {
-                        return Err(helper::check_handle_warning_with(handle_with, message));
+                        Err(helper::check_handle_warning_with(handle_with, message))
                     }
                 }
             }
@@ -244,18 +261,18 @@ fn check_single_path(_py: Python,
                 let elements = vec![py_exception, py_list];
                 let result: &PyAny = PyTuple::new(_py, elements);
 
-                return Ok(Some(Py::from(result)));
+                Ok(Some(Py::from(result)))
             }
-            _ => { return Err(PyRuntimeError::new_err("Something went wrong.")); }
+            _ => { Err(PyRuntimeError::new_err("Something went wrong.")) }
         }
     }
 }
 
 #[pyfunction]
 pub unsafe fn assert_paths(_py: Python, paths: PyObject) -> Result<Option<Py<PyAny>>, PyErr> {
-    return check_if_paths_exist(_py,
-                                paths,
-                                Option::from(PyAssertionError::new_err(()).get_type(_py)),
-                                Option::from(""),
-                                Option::from("raise"));
+    check_if_paths_exist(_py,
+                         paths,
+                         Option::from(PyAssertionError::new_err(()).get_type(_py)),
+                         Option::from(""),
+                         Option::from("raise"))
 }
\ No newline at end of file
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Mutex, OnceLock};
+
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyValueError};
+use pyo3::types::{PyBytes, PyString, PyType};
+
+use crate::helper;
+
+/// Maximum number of compiled patterns kept across calls.
+const CACHE_SIZE: usize = 512;
+
+/// Maximum number of failing items listed in the default message.
+const MAX_REPORTED: usize = 10;
+
+#[derive(Clone)]
+enum Pattern {
+    Text(regex::Regex),
+    Bytes(regex::bytes::Regex),
+}
+
+impl Pattern {
+    /// Whether `value` matches, or None if value is of the wrong type. A str
+    /// which cannot be encoded as UTF-8, e.g. a lone surrogate, raises.
+    fn is_match(&self, value: &PyAny) -> PyResult<Option<bool>> {
+        match self {
+            Pattern::Text(pattern) => {
+                match value.downcast::<PyString>() {
+                    Ok(text) => { Ok(Some(pattern.is_match(text.to_str()?))) }
+                    Err(_) => { Ok(None) }
+                }
+            }
+            Pattern::Bytes(pattern) => { Ok(value.downcast::<PyBytes>().ok().map(|bytes| pattern.is_match(bytes.as_bytes()))) }
+        }
+    }
+
+    fn value_type(&self) -> &str {
+        match self {
+            Pattern::Text(_) => { "str" }
+            Pattern::Bytes(_) => { "bytes" }
+        }
+    }
+}
+
+/// Compiled patterns by their source, whether they must match fully and
+/// whether they match bytes.
+type PatternCache = Mutex<HashMap<(String, bool, bool), Pattern>>;
+
+fn cache() -> &'static PatternCache {
+    static CACHE: OnceLock<PatternCache> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The source of a bytes pattern with every non-ASCII byte written as a
+/// `\xNN` escape, so that patterns need not be valid UTF-8 and raw bytes
+/// match themselves, as in Python's re.
+fn escape_bytes_pattern(source: &[u8]) -> String {
+    let mut escaped = String::with_capacity(source.len());
+    for &byte in source {
+        if byte.is_ascii() {
+            escaped.push(byte as char);
+        } else {
+            // a backslash in front of the byte only escapes it
+            if escaped.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1 {
+                escaped.pop();
+            }
+            escaped.push_str(&format!("\\x{:02X}", byte));
+        }
+    }
+    escaped
+}
+
+fn compile(source: &str, full: bool, bytes: bool) -> PyResult<Pattern> {
+    let key = (source.to_string(), full, bytes);
+    if let Some(pattern) = cache().lock().unwrap().get(&key) {
+        return Ok(pattern.clone());
+    }
+
+    let anchored = if full { format!(r"\A(?:{})\z", source) } else { source.to_string() };
+    let invalid = |err: regex::Error| PyValueError::new_err(format!("invalid pattern {:?}: {}", source, err));
+    let pattern = if bytes {
+        // without Unicode mode, `.` and classes match single bytes
+        Pattern::Bytes(regex::bytes::RegexBuilder::new(&anchored).unicode(false).build().map_err(invalid)?)
+    } else {
+        Pattern::Text(regex::Regex::new(&anchored).map_err(invalid)?)
+    };
+
+    let mut cache = cache().lock().unwrap();
+    if cache.len() >= CACHE_SIZE {
+        cache.clear();
+    }
+    cache.insert(key, pattern.clone());
+    Ok(pattern)
+}
+
+/// Check that a string, or every string of an iterable, matches a regular
+/// expression.
+///
+/// Patterns use the syntax of Rust's regex crate, which is close to that of
+/// Python's re module but has no look-around or backreferences. Compiled
+/// patterns are cached across calls.
+///
+/// Args:
+///     value (str, bytes or Iterable): value(s) to check; bytes need a bytes
+///         pattern and str a str pattern. Bytes patterns match byte by byte,
+///         like bytes patterns of Python's re.
+///     pattern (str or bytes): the regular expression
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the failing values.
+///     full (bool): require the whole value to match, as re.fullmatch,
+///         instead of matching anywhere in it, as re.search
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     ValueError by default. For an iterable, the exception has an
+///     `indices` attribute, the indices of the failing items.
+///
+/// >>> check_matches(["AB-12", "ab-3", "CD-45"], r"[A-Z]{2}-\d+")
+/// Traceback (most recent call last):
+///     ...
+/// ValueError: 1 of 3 items do not fully match '[A-Z]{2}-\\d+': [1] 'ab-3'
+#[pyfunction]
+#[pyo3(signature = (value, pattern, /, handle_with=None, message=None, *, full=true))]
+pub fn check_matches(_py: Python,
+                     value: &PyAny,
+                     pattern: &PyAny,
+                     handle_with: Option<&PyType>,
+                     message: Option<&str>,
+                     full: bool) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let compiled = if let Ok(source) = pattern.downcast::<PyString>() {
+        compile(source.to_str()?, full, false)?
+    } else if let Ok(source) = pattern.downcast::<PyBytes>() {
+        compile(&escape_bytes_pattern(source.as_bytes()), full, true)?
+    } else {
+        return Err(PyTypeError::new_err(format!("pattern must be str or bytes, got '{}'", pattern.get_type().name()?)));
+    };
+    let how = if full { "fully match" } else { "match" };
+
+    if value.is_instance_of::<PyString>() || value.is_instance_of::<PyBytes>() {
+        let default_message = match compiled.is_match(value)? {
+            Some(true) => { return Ok(()) }
+            Some(false) => { format!("{} does not {} {}", value.repr()?, how, pattern.repr()?) }
+            None => {
+                return Err(PyTypeError::new_err(format!("cannot match a {} pattern against '{}'", compiled.value_type(), value.get_type().name()?)));
+            }
+        };
+        let message = match message {
+            None => { default_message.as_str() }
+            Some(msg) => { msg }
+        };
+        return match handle_with {
+            None => { Err(PyValueError::new_err(message.to_string())) }
+            Some(_) => { Err(helper::check_handle_warning_with(handle_with, Some(message))) }
+        };
+    }
+
+    let mut indices: Vec<usize> = Vec::new();
+    let mut described: Vec<String> = Vec::new();
+    let mut count = 0;
+    for (idx, item) in value.iter()?.enumerate() {
+        let item = item?;
+        count += 1;
+        match compiled.is_match(item)? {
+            Some(true) => {}
+            Some(false) => {
+                indices.push(idx);
+                if described.len() < MAX_REPORTED {
+                    described.push(format!("[{}] {}", idx, item.repr()?));
+                }
+            }
+            None => {
+                return Err(PyTypeError::new_err(format!("item [{}]: expected {}, got '{}'", idx, compiled.value_type(), item.get_type().name()?)));
+            }
+        }
+    }
+
+    if indices.is_empty() {
+        return Ok(());
+    }
+
+    if indices.len() > described.len() {
+        described.push(format!("and {} more", indices.len() - described.len()));
+    }
+    let default_message = format!("{} of {} items do not {} {}: {}", indices.len(), count, how, pattern.repr()?, described.join(", "));
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = match handle_with {
+        None => { PyValueError::new_err(message.to_string()) }
+        Some(_) => { helper::check_handle_warning_with(handle_with, Some(message)) }
+    };
+    Err(helper::with_attributes(_py, err, vec![("indices", indices.into_py(_py))]))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (value, pattern, /, handle_with=None, message=None, *, full=true))]
+pub fn assert_matches(_py: Python,
+                      value: &PyAny,
+                      pattern: &PyAny,
+                      handle_with: Option<&PyType>,
+                      message: Option<&str>,
+                      full: bool) -> PyResult<()> {
+    check_matches(_py, value, pattern, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, full)
+}
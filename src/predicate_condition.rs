@@ -0,0 +1,290 @@
+use std::env;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyKeyboardInterrupt, PySystemExit, PyTypeError, PyValueError};
+use pyo3::types::{PyDict, PyString, PyTuple, PyType};
+
+use crate::helper;
+
+/// Look up one of the checks of this module by its name.
+fn builtin_check<'py>(module: &'py PyModule, name: &str) -> PyResult<&'py PyAny> {
+    let names: Vec<String> = module.dir().iter()
+        .filter_map(|name| name.extract::<String>().ok())
+        .filter(|name| name.starts_with("check_"))
+        .collect();
+
+    if names.iter().any(|check| check == name) {
+        return module.getattr(name);
+    }
+    let mut msg = format!("unknown check '{}'", name);
+    if let Some(suggested) = helper::closest_match(name, names.iter().map(String::as_str)) {
+        msg.push_str(&format!("; did you mean '{}'?", suggested));
+    }
+    Err(PyValueError::new_err(msg))
+}
+
+/// A predicate applied to the elements of an iterable.
+enum Predicate<'py> {
+    /// Any callable, which fails when its result is falsy.
+    Callable(&'py PyAny),
+    /// One of the checks of this module, which fails when it raises.
+    Check(&'py PyAny),
+}
+
+impl<'py> Predicate<'py> {
+    /// Resolve the predicate; for a check, its extra arguments are validated
+    /// once here, so that they do not turn into a failure of every element.
+    fn new(module: &'py PyModule, predicate: &'py PyAny, args: &PyTuple, kwargs: Option<&PyDict>) -> PyResult<Self> {
+        let _py = module.py();
+        let name = match predicate.downcast::<PyString>() {
+            Ok(name) => { name.to_str()? }
+            Err(_) => { return Ok(Predicate::Callable(predicate)) }
+        };
+        let check = builtin_check(module, name)?;
+
+        if let Ok(signature) = _py.import("inspect")?.call_method1("signature", (check,)) {
+            let bound_args = PyTuple::new(_py, std::iter::once(_py.None().into_ref(_py)).chain(args.iter()).collect::<Vec<_>>());
+            if let Err(err) = signature.call_method("bind", bound_args, kwargs) {
+                if err.is_instance_of::<PyTypeError>(_py) {
+                    return Err(PyTypeError::new_err(format!("{}() {}", name, err.value(_py))));
+                }
+                return Err(err);
+            }
+        }
+        Ok(Predicate::Check(check))
+    }
+
+    /// Apply the predicate; returns the reason of the failure, if any.
+    fn failure(&self, _py: Python, element: &PyAny, args: &PyTuple, kwargs: Option<&PyDict>) -> PyResult<Option<String>> {
+        let call_args = PyTuple::new(_py, std::iter::once(element).chain(args.iter()).collect::<Vec<_>>());
+        match self {
+            Predicate::Callable(func) => {
+                Ok((!func.call(call_args, kwargs)?.is_true()?).then(String::new))
+            }
+            Predicate::Check(check) => {
+                match check.call(call_args, kwargs) {
+                    Ok(_) => { Ok(None) }
+                    Err(err) if err.is_instance_of::<PyKeyboardInterrupt>(_py) || err.is_instance_of::<PySystemExit>(_py) => { Err(err) }
+                    Err(err) => {
+                        let name = err.get_type(_py).name()?;
+                        let text = err.value(_py).to_string();
+                        Ok(Some(if text.is_empty() { name.to_string() } else { format!("{}: {}", name, text) }))
+                    }
+                }
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Predicate::Callable(func) => {
+                match func.getattr("__qualname__") {
+                    Ok(name) => { name.to_string() }
+                    Err(_) => { func.to_string() }
+                }
+            }
+            Predicate::Check(check) => { check.getattr("__name__").map(|name| name.to_string()).unwrap_or_default() }
+        }
+    }
+}
+
+fn describe_failures(_py: Python, failures: &[(usize, &PyAny, String)], total: usize, max_failures: usize) -> PyResult<String> {
+    let mut described: Vec<String> = Vec::new();
+    for (idx, element, reason) in failures.iter().take(max_failures) {
+        let mut text = format!("[{}] {}", idx, element.repr()?);
+        if !reason.is_empty() {
+            text.push_str(&format!(" ({})", reason));
+        }
+        described.push(text);
+    }
+    if total > described.len() {
+        described.push(format!("and {} more", total - described.len()));
+    }
+    Ok(described.join(", "))
+}
+
+fn raise_predicate_error(_py: Python, handle_with: Option<&PyType>, message: Option<&str>, default_message: String, indices: Vec<usize>) -> PyResult<()> {
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    let err = helper::check_handle_warning_with(handle_with, Some(message));
+    Err(helper::with_attributes(_py, err, vec![("indices", indices.into_py(_py))]))
+}
+
+/// Check that a predicate holds for all elements of an iterable.
+///
+/// Args:
+///     items (Iterable): elements to check
+///     predicate (Callable or str): a callable, which fails for an element
+///         when its result is falsy, or the name of a check of this module,
+///         e.g. "check_if_in_limits", which fails when it raises. Any
+///         further positional and keyword arguments are passed to the
+///         predicate after the element; for a check they are validated
+///         against its signature before any element is checked.
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the failing elements.
+///     max_failures (int): maximum number of failing elements listed in the
+///         message
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     AssertionError by default. The exception has an `indices` attribute,
+///     the indices of all failing elements.
+///
+/// >>> check_all([3, -1, 5, -2], lambda x: x > 0)
+/// Traceback (most recent call last):
+///     ...
+/// AssertionError: <lambda> fails for 2 of 4 items: [1] -1, [3] -2
+/// >>> check_all(["ab", "abc"], "check_length", 2)
+/// Traceback (most recent call last):
+///     ...
+/// AssertionError: check_length fails for 1 of 2 items: [1] 'abc' (LengthError)
+#[pyfunction]
+#[pyo3(pass_module, signature = (items, predicate, /, *args, handle_with=None, message=None, max_failures=10, **kwargs))]
+#[allow(clippy::too_many_arguments)]
+pub fn check_all(module: &PyModule,
+                 items: &PyAny,
+                 predicate: &PyAny,
+                 args: &PyTuple,
+                 handle_with: Option<&PyType>,
+                 message: Option<&str>,
+                 max_failures: usize,
+                 kwargs: Option<&PyDict>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let _py = module.py();
+    let predicate = Predicate::new(module, predicate, args, kwargs)?;
+    let mut failures: Vec<(usize, &PyAny, String)> = Vec::new();
+    let mut indices: Vec<usize> = Vec::new();
+    let mut count = 0;
+
+    for (idx, element) in items.iter()?.enumerate() {
+        let element = element?;
+        count += 1;
+        if let Some(reason) = predicate.failure(_py, element, args, kwargs)? {
+            indices.push(idx);
+            if failures.len() < max_failures {
+                failures.push((idx, element, reason));
+            }
+        }
+    }
+
+    if indices.is_empty() {
+        return Ok(());
+    }
+
+    let default_message = format!("{} fails for {} of {} items: {}",
+                                  predicate.name(), indices.len(), count,
+                                  describe_failures(_py, &failures, indices.len(), max_failures)?);
+    raise_predicate_error(_py, handle_with, message, default_message, indices)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(pass_module, signature = (items, predicate, /, *args, handle_with=None, message=None, max_failures=10, **kwargs))]
+pub fn assert_all(module: &PyModule,
+                  items: &PyAny,
+                  predicate: &PyAny,
+                  args: &PyTuple,
+                  handle_with: Option<&PyType>,
+                  message: Option<&str>,
+                  max_failures: usize,
+                  kwargs: Option<&PyDict>) -> PyResult<()> {
+    check_all(module, items, predicate, args, Option::from(PyAssertionError::new_err(()).get_type(module.py())), message, max_failures, kwargs)
+}
+
+/// Check that a predicate holds for at least one element of an iterable.
+///
+/// Elements are only evaluated until the predicate holds for one of them.
+///
+/// Args:
+///     items (Iterable): elements to check
+///     predicate (Callable or str): a callable or the name of a check of this
+///         module, as in check_all. Any further positional and keyword
+///         arguments are passed to the predicate after the element.
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means listing the failing elements.
+///     max_failures (int): maximum number of failing elements listed in the
+///         message
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     AssertionError by default. The exception has an `indices` attribute,
+///     the indices of all (failing) elements.
+///
+/// >>> check_any([-1, -2], lambda x: x > 0)
+/// Traceback (most recent call last):
+///     ...
+/// AssertionError: <lambda> fails for all 2 items: [0] -1, [1] -2
+#[pyfunction]
+#[pyo3(pass_module, signature = (items, predicate, /, *args, handle_with=None, message=None, max_failures=10, **kwargs))]
+#[allow(clippy::too_many_arguments)]
+pub fn check_any(module: &PyModule,
+                 items: &PyAny,
+                 predicate: &PyAny,
+                 args: &PyTuple,
+                 handle_with: Option<&PyType>,
+                 message: Option<&str>,
+                 max_failures: usize,
+                 kwargs: Option<&PyDict>) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let _py = module.py();
+    let predicate = Predicate::new(module, predicate, args, kwargs)?;
+    let mut failures: Vec<(usize, &PyAny, String)> = Vec::new();
+    let mut indices: Vec<usize> = Vec::new();
+
+    for (idx, element) in items.iter()?.enumerate() {
+        let element = element?;
+        match predicate.failure(_py, element, args, kwargs)? {
+            None => { return Ok(()) }
+            Some(reason) => {
+                indices.push(idx);
+                if failures.len() < max_failures {
+                    failures.push((idx, element, reason));
+                }
+            }
+        }
+    }
+
+    let default_message = match indices.len() {
+        0 => { format!("{} cannot hold for an empty iterable", predicate.name()) }
+        count => {
+            format!("{} fails for all {} items: {}",
+                    predicate.name(), count, describe_failures(_py, &failures, count, max_failures)?)
+        }
+    };
+    raise_predicate_error(_py, handle_with, message, default_message, indices)
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(pass_module, signature = (items, predicate, /, *args, handle_with=None, message=None, max_failures=10, **kwargs))]
+pub fn assert_any(module: &PyModule,
+                  items: &PyAny,
+                  predicate: &PyAny,
+                  args: &PyTuple,
+                  handle_with: Option<&PyType>,
+                  message: Option<&str>,
+                  max_failures: usize,
+                  kwargs: Option<&PyDict>) -> PyResult<()> {
+    check_any(module, items, predicate, args, Option::from(PyAssertionError::new_err(()).get_type(module.py())), message, max_failures, kwargs)
+}
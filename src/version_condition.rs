@@ -0,0 +1,611 @@
+use std::cmp::Ordering;
+use std::env;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyAssertionError, PyBaseException, PyValueError};
+use pyo3::types::{PyModule, PyString, PyType};
+use regex::Regex;
+
+use crate::helper;
+
+pyo3::create_exception!(reasycheck, VersionError, PyBaseException);
+
+/// The version pattern of PEP 440, including the alternative spellings which
+/// are normalized, e.g. "1.0-beta.2" for "1.0b2".
+const PEP440: &str = r"(?ix)^\s*v?
+    (?:(?P<epoch>[0-9]+)!)?
+    (?P<release>[0-9]+(?:\.[0-9]+)*)
+    (?P<pre>[-_.]?(?P<pre_l>alpha|a|beta|b|preview|pre|c|rc)[-_.]?(?P<pre_n>[0-9]+)?)?
+    (?P<post>(?:-(?P<post_n1>[0-9]+))|(?:[-_.]?(?P<post_l>post|rev|r)[-_.]?(?P<post_n2>[0-9]+)?))?
+    (?P<dev>[-_.]?(?P<dev_l>dev)[-_.]?(?P<dev_n>[0-9]+)?)?
+    (?:\+(?P<local>[a-z0-9]+(?:[-_.][a-z0-9]+)*))?
+    \s*$";
+
+/// A segment of a local version label; numbers sort after strings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum LocalSegment {
+    Text(String),
+    Number(u64),
+}
+
+#[derive(Debug, Clone)]
+struct Pep440Version {
+    epoch: u64,
+    release: Vec<u64>,
+    /// kind (0 = a, 1 = b, 2 = rc) and number
+    pre: Option<(u8, u64)>,
+    post: Option<u64>,
+    dev: Option<u64>,
+    local: Option<Vec<LocalSegment>>,
+}
+
+impl Pep440Version {
+    fn is_prerelease(&self) -> bool {
+        self.pre.is_some() || self.dev.is_some()
+    }
+
+    /// The version without local label.
+    fn public(&self) -> Pep440Version {
+        Pep440Version { local: None, ..self.clone() }
+    }
+
+    /// Epoch and release only.
+    fn base(&self) -> Pep440Version {
+        Pep440Version { epoch: self.epoch, release: self.release.clone(), pre: None, post: None, dev: None, local: None }
+    }
+
+    /// The sort key of PEP 440: trailing zeros of the release don't matter,
+    /// and dev releases sort before pre-releases, which sort before the final
+    /// release, which sorts before post releases.
+    #[allow(clippy::type_complexity)]
+    fn key(&self) -> (u64, &[u64], (u8, u8, u64), (u8, u64), (u8, u64), (u8, &[LocalSegment])) {
+        let trailing_zeros = self.release.iter().rev().take_while(|part| **part == 0).count();
+        let release = &self.release[..self.release.len() - trailing_zeros];
+        let pre = match (self.pre, self.post, self.dev) {
+            (None, None, Some(_)) => { (0, 0, 0) }
+            (Some((kind, number)), _, _) => { (1, kind, number) }
+            (None, _, _) => { (2, 0, 0) }
+        };
+        let post = self.post.map_or((0, 0), |number| (1, number));
+        let dev = self.dev.map_or((1, 0), |number| (0, number));
+        let local = self.local.as_deref().map_or((0, &[][..]), |local| (1, local));
+        (self.epoch, release, pre, post, dev, local)
+    }
+}
+
+impl PartialEq for Pep440Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Pep440Version {}
+
+impl Ord for Pep440Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl PartialOrd for Pep440Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Pep440Version {
+    type Err = String;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        let invalid = || format!("invalid PEP 440 version '{}'", version);
+        let number = |text: &str| text.parse::<u64>().map_err(|_| invalid());
+        let captures = PATTERN.get_or_init(|| Regex::new(PEP440).expect("valid built-in pattern"))
+            .captures(version).ok_or_else(invalid)?;
+
+        let epoch = captures.name("epoch").map_or(Ok(0), |epoch| number(epoch.as_str()))?;
+        let release = captures["release"].split('.').map(number).collect::<Result<Vec<_>, _>>()?;
+        let pre = match captures.name("pre_l") {
+            Some(label) => {
+                let kind = match label.as_str().to_lowercase().as_str() {
+                    "a" | "alpha" => { 0 }
+                    "b" | "beta" => { 1 }
+                    _ => { 2 }
+                };
+                Some((kind, captures.name("pre_n").map_or(Ok(0), |n| number(n.as_str()))?))
+            }
+            None => { None }
+        };
+        let post = match (captures.name("post_n1"), captures.name("post_l")) {
+            (Some(n), _) => { Some(number(n.as_str())?) }
+            (None, Some(_)) => { Some(captures.name("post_n2").map_or(Ok(0), |n| number(n.as_str()))?) }
+            (None, None) => { None }
+        };
+        let dev = match captures.name("dev_l") {
+            Some(_) => { Some(captures.name("dev_n").map_or(Ok(0), |n| number(n.as_str()))?) }
+            None => { None }
+        };
+        let local = captures.name("local").map(|local| {
+            local.as_str().split(['-', '_', '.'])
+                .map(|segment| match segment.parse::<u64>() {
+                    Ok(n) => { LocalSegment::Number(n) }
+                    Err(_) => { LocalSegment::Text(segment.to_lowercase()) }
+                })
+                .collect()
+        });
+        Ok(Pep440Version { epoch, release, pre, post, dev, local })
+    }
+}
+
+/// Whether a PEP 440 version satisfies a single specifier such as ">=1.4",
+/// "~=2.2" or "==1.*".
+fn pep440_matches(version: &Pep440Version, raw_version: &str, specifier: &str) -> Result<bool, String> {
+    let invalid = || format!("invalid PEP 440 specifier '{}'", specifier);
+    let operator_end = specifier.find(|c: char| !"<>=!~".contains(c)).ok_or_else(invalid)?;
+    let (operator, target) = (&specifier[..operator_end], specifier[operator_end..].trim());
+
+    if operator == "===" {
+        return Ok(raw_version.trim().eq_ignore_ascii_case(target));
+    }
+
+    if let Some(prefix) = target.strip_suffix(".*") {
+        let prefix = Pep440Version::from_str(prefix).map_err(|_| invalid())?;
+        if prefix.pre.is_some() || prefix.post.is_some() || prefix.dev.is_some() || prefix.local.is_some() {
+            return Err(invalid());
+        }
+        let mut release = version.release.clone();
+        release.resize(release.len().max(prefix.release.len()), 0);
+        let matches = version.epoch == prefix.epoch && release[..prefix.release.len()] == prefix.release[..];
+        return match operator {
+            "==" => { Ok(matches) }
+            "!=" => { Ok(!matches) }
+            _ => { Err(invalid()) }
+        };
+    }
+
+    let target = Pep440Version::from_str(target).map_err(|_| invalid())?;
+    if target.local.is_some() && operator != "==" && operator != "!=" {
+        return Err(invalid());
+    }
+    // a local version matches a specifier without one, e.g. 1.0+cpu == 1.0
+    let candidate = if target.local.is_none() { version.public() } else { version.clone() };
+    match operator {
+        "==" => { Ok(candidate == target) }
+        "!=" => { Ok(candidate != target) }
+        "<=" => { Ok(candidate <= target) }
+        ">=" => { Ok(candidate >= target) }
+        // <1.0 doesn't accept pre-releases of 1.0
+        "<" => { Ok(candidate < target && !(version.is_prerelease() && !target.is_prerelease() && version.base() == target.base())) }
+        // >1.0 accepts neither post releases of 1.0 nor 1.0+local
+        ">" => {
+            Ok(candidate > target
+                && !(version.post.is_some() && target.post.is_none() && version.base() == target.base())
+                && !(version.local.is_some() && version.base() == target.base()))
+        }
+        "~=" => {
+            if target.release.len() < 2 {
+                return Err(invalid());
+            }
+            let prefix = &target.release[..target.release.len() - 1];
+            let mut release = version.release.clone();
+            release.resize(release.len().max(prefix.len()), 0);
+            Ok(candidate >= target && version.epoch == target.epoch && release[..prefix.len()] == prefix[..])
+        }
+        _ => { Err(invalid()) }
+    }
+}
+
+fn pep440_satisfies(version: &str, spec: &str) -> Result<bool, String> {
+    let parsed = Pep440Version::from_str(version)?;
+    let mut satisfied = true;
+    for specifier in spec.split(',').map(str::trim).filter(|specifier| !specifier.is_empty()) {
+        satisfied &= pep440_matches(&parsed, version, specifier)?;
+    }
+    Ok(satisfied)
+}
+
+/// A pre-release identifier of a semantic version; numbers sort before
+/// alphanumeric identifiers.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+    Number(u64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SemVer {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<Identifier>,
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => { Ordering::Equal }
+                (true, false) => { Ordering::Greater }
+                (false, true) => { Ordering::Less }
+                (false, false) => { self.pre.cmp(&other.pre) }
+            })
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A version in a semver range, where trailing parts may be missing or
+/// wildcards, e.g. "1.2", "1.x" or "*".
+struct PartialVersion {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Vec<Identifier>,
+}
+
+impl PartialVersion {
+    fn parse(version: &str) -> Option<PartialVersion> {
+        let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+        let version = version.split_once('+').map_or(version, |(version, build)| {
+            if build.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')) { version } else { "" }
+        });
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => { (core, Some(pre)) }
+            None => { (version, None) }
+        };
+        let numeric = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()) && (part == "0" || !part.starts_with('0'));
+        let mut parts: Vec<Option<u64>> = Vec::new();
+        for part in core.split('.') {
+            match part {
+                "x" | "X" | "*" => { parts.push(None) }
+                part if numeric(part) => { parts.push(Some(part.parse().ok()?)) }
+                _ => { return None }
+            }
+        }
+        if parts.len() > 3 || parts.windows(2).any(|pair| pair[0].is_none() && pair[1].is_some()) {
+            return None;
+        }
+        parts.resize(3, None);
+
+        let pre = match pre {
+            None => { Vec::new() }
+            Some(pre) => {
+                // only full versions have pre-releases
+                parts[2]?;
+                pre.split('.').map(|part| {
+                    if numeric(part) {
+                        part.parse().ok().map(Identifier::Number)
+                    } else if !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') && !part.chars().all(|c| c.is_ascii_digit()) {
+                        Some(Identifier::Text(part.to_string()))
+                    } else {
+                        None
+                    }
+                }).collect::<Option<Vec<_>>>()?
+            }
+        };
+        Some(PartialVersion { major: parts[0], minor: parts[1], patch: parts[2], pre })
+    }
+
+    fn to_full(&self) -> Option<SemVer> {
+        Some(SemVer { major: self.major?, minor: self.minor?, patch: self.patch?, pre: self.pre.clone() })
+    }
+
+    /// The lowest version matching the partial version.
+    fn lowest(&self) -> SemVer {
+        SemVer { major: self.major.unwrap_or(0), minor: self.minor.unwrap_or(0), patch: self.patch.unwrap_or(0), pre: self.pre.clone() }
+    }
+}
+
+/// The lowest pre-release of a version, so that `< floor(2, 0, 0)` excludes
+/// the pre-releases of 2.0.0 as well.
+fn floor(major: u64, minor: u64, patch: u64) -> SemVer {
+    SemVer { major, minor, patch, pre: vec![Identifier::Number(0)] }
+}
+
+/// The lowest pre-release above all versions `major[.minor[.patch]]`, e.g.
+/// 1.3.0-0 for 1.2. A part at its maximum carries over into the next one;
+/// None if no version lies above.
+fn bump(major: u64, minor: Option<u64>, patch: Option<u64>) -> Option<SemVer> {
+    match (minor, patch) {
+        (None, _) => { major.checked_add(1).map(|major| floor(major, 0, 0)) }
+        (Some(minor), None) => {
+            match minor.checked_add(1) {
+                Some(minor) => { Some(floor(major, minor, 0)) }
+                None => { bump(major, None, None) }
+            }
+        }
+        (Some(minor), Some(patch)) => {
+            match patch.checked_add(1) {
+                Some(patch) => { Some(floor(major, minor, patch)) }
+                None => { bump(major, Some(minor), None) }
+            }
+        }
+    }
+}
+
+/// The version just above all versions matching the partial version, e.g.
+/// 1.3.0-0 for "1.2", or None for "*" and when no version lies above.
+fn next_after(partial: &PartialVersion) -> Option<SemVer> {
+    bump(partial.major?, partial.minor, partial.patch)
+}
+
+enum Bound {
+    Lt(SemVer),
+    Le(SemVer),
+    Gt(SemVer),
+    Ge(SemVer),
+    Eq(SemVer),
+}
+
+impl Bound {
+    fn accepts(&self, version: &SemVer) -> bool {
+        match self {
+            Bound::Lt(bound) => { version < bound }
+            Bound::Le(bound) => { version <= bound }
+            Bound::Gt(bound) => { version > bound }
+            Bound::Ge(bound) => { version >= bound }
+            Bound::Eq(bound) => { version == bound }
+        }
+    }
+}
+
+/// Translate one comparator of a semver range, e.g. "^1.2" or ">=1.0.0",
+/// into bounds.
+fn semver_bounds(comparator: &str) -> Option<Vec<Bound>> {
+    let operator_end = comparator.find(|c: char| !"<>=^~".contains(c)).unwrap_or(comparator.len());
+    let (operator, version) = (&comparator[..operator_end], comparator[operator_end..].trim());
+    let partial = PartialVersion::parse(version)?;
+    let nothing = || vec![Bound::Lt(floor(0, 0, 0))];
+
+    let bounds = match operator {
+        "" | "=" => {
+            match (partial.to_full(), next_after(&partial)) {
+                (Some(version), _) => { vec![Bound::Eq(version)] }
+                (None, Some(next)) => { vec![Bound::Ge(partial.lowest()), Bound::Lt(next)] }
+                (None, None) if partial.major.is_none() => { vec![] }
+                (None, None) => { vec![Bound::Ge(partial.lowest())] }
+            }
+        }
+        ">" => {
+            match (partial.to_full(), next_after(&partial)) {
+                (Some(version), _) => { vec![Bound::Gt(version)] }
+                (None, Some(next)) => { vec![Bound::Ge(SemVer { pre: vec![], ..next })] }
+                (None, None) => { nothing() }
+            }
+        }
+        ">=" => { vec![Bound::Ge(partial.lowest())] }
+        "<" => {
+            match (partial.to_full(), partial.major) {
+                (Some(version), _) => { vec![Bound::Lt(version)] }
+                (None, Some(_)) => { vec![Bound::Lt(SemVer { pre: vec![Identifier::Number(0)], ..partial.lowest() })] }
+                (None, None) => { nothing() }
+            }
+        }
+        "<=" => {
+            match (partial.to_full(), next_after(&partial)) {
+                (Some(version), _) => { vec![Bound::Le(version)] }
+                (None, Some(next)) => { vec![Bound::Lt(next)] }
+                (None, None) => { vec![] }
+            }
+        }
+        "^" => {
+            let upper = match (partial.major, partial.minor, partial.patch) {
+                (None, _, _) => { None }
+                (Some(major), None, _) => { bump(major, None, None) }
+                (Some(0), Some(minor), None) => { bump(0, Some(minor), None) }
+                (Some(0), Some(0), Some(patch)) => { bump(0, Some(0), Some(patch)) }
+                (Some(0), Some(minor), Some(_)) => { bump(0, Some(minor), None) }
+                (Some(major), _, _) => { bump(major, None, None) }
+            };
+            let mut bounds = vec![Bound::Ge(partial.lowest())];
+            bounds.extend(upper.map(Bound::Lt));
+            bounds
+        }
+        "~" => {
+            let upper = match (partial.major, partial.minor) {
+                (None, _) => { None }
+                (Some(major), None) => { bump(major, None, None) }
+                (Some(major), Some(minor)) => { bump(major, Some(minor), None) }
+            };
+            let mut bounds = vec![Bound::Ge(partial.lowest())];
+            bounds.extend(upper.map(Bound::Lt));
+            bounds
+        }
+        _ => { return None }
+    };
+    Some(bounds)
+}
+
+/// Whether a semantic version satisfies a range such as "^1.2", ">=1.0.0 <2",
+/// "1.2.3 - 1.4" or "~1.2 || ^2".
+fn semver_satisfies(version: &str, spec: &str) -> Result<bool, String> {
+    let parsed = PartialVersion::parse(version.trim()).and_then(|version| version.to_full())
+        .ok_or_else(|| format!("invalid semantic version '{}'", version))?;
+    let invalid = || format!("invalid semver range '{}'", spec);
+
+    for alternative in spec.split("||") {
+        // operators may be separated from their version, e.g. ">= 1.2"
+        let mut tokens: Vec<String> = Vec::new();
+        for token in alternative.split([' ', ',']).filter(|token| !token.is_empty()) {
+            match tokens.last_mut() {
+                Some(last) if !last.is_empty() && last.chars().all(|c| "<>=^~".contains(c)) => { last.push_str(token) }
+                _ => { tokens.push(token.to_string()) }
+            }
+        }
+
+        let mut bounds: Vec<Bound> = Vec::new();
+        let mut idx = 0;
+        while idx < tokens.len() {
+            if tokens.get(idx + 1).is_some_and(|token| token == "-") {
+                // a hyphen range, e.g. "1.2.3 - 2.3"
+                let upper = tokens.get(idx + 2).ok_or_else(invalid)?;
+                bounds.extend(semver_bounds(&format!(">={}", tokens[idx])).ok_or_else(invalid)?);
+                bounds.extend(semver_bounds(&format!("<={}", upper)).ok_or_else(invalid)?);
+                idx += 3;
+            } else {
+                bounds.extend(semver_bounds(&tokens[idx]).ok_or_else(invalid)?);
+                idx += 1;
+            }
+        }
+        if bounds.iter().all(|bound| bound.accepts(&parsed)) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn satisfies(version: &str, spec: &str, scheme: &str) -> PyResult<bool> {
+    let satisfied = match scheme {
+        "pep440" => { pep440_satisfies(version, spec) }
+        "semver" => { semver_satisfies(version, spec) }
+        _ => { return Err(PyValueError::new_err("scheme can only be `pep440` or `semver`")) }
+    };
+    satisfied.map_err(PyValueError::new_err)
+}
+
+fn raise_version_error(_py: Python, handle_with: Option<&PyType>, message: Option<&str>, default_message: String) -> PyResult<()> {
+    let message = match message {
+        None => { default_message.as_str() }
+        Some(msg) => { msg }
+    };
+    match handle_with {
+        None => { Err(helper::check_handle_warning_with(Some(_py.get_type::<VersionError>()), Some(message))) }
+        Some(_) => { Err(helper::check_handle_warning_with(handle_with, Some(message))) }
+    }
+}
+
+/// Check that a version satisfies a version constraint.
+///
+/// Args:
+///     version (str): the version, e.g. "1.4.2"; any object is converted
+///         with str()
+///     spec (str): the constraint. For PEP 440, a comma-separated list of
+///         specifiers, e.g. ">=1.4,<2", "~=2.2" or "==1.*"; pre-releases
+///         are accepted when they satisfy it. For semver, a range as used
+///         by npm and cargo, e.g. "^1.4", "~1.4.2", ">=1.4.0 <2",
+///         "1.4 - 1.6" or "^1 || ^2".
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming the version and spec.
+///     scheme (str): "pep440" (the default) or "semver"
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     VersionError by default; ValueError for an invalid version or spec.
+///
+/// >>> check_version("2.0.0rc1", ">=1.4,<2")
+/// Traceback (most recent call last):
+///     ...
+/// VersionError: version 2.0.0rc1 does not satisfy >=1.4,<2
+#[pyfunction]
+#[pyo3(signature = (version, spec, /, handle_with=None, message=None, *, scheme="pep440"))]
+pub fn check_version(_py: Python,
+                     version: &PyAny,
+                     spec: &str,
+                     handle_with: Option<&PyType>,
+                     message: Option<&str>,
+                     scheme: &str) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let version = version.str()?.to_string();
+    if satisfies(&version, spec, scheme)? {
+        return Ok(());
+    }
+    raise_version_error(_py, handle_with, message, format!("version {} does not satisfy {}", version, spec))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (version, spec, /, handle_with=None, message=None, *, scheme="pep440"))]
+pub fn assert_version(_py: Python,
+                      version: &PyAny,
+                      spec: &str,
+                      handle_with: Option<&PyType>,
+                      message: Option<&str>,
+                      scheme: &str) -> PyResult<()> {
+    check_version(_py, version, spec, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, scheme)
+}
+
+/// Check that an installed package satisfies a version constraint.
+///
+/// The version is the `__version__` attribute of the module, or else the
+/// version of the installed distribution of that name, as given by
+/// importlib.metadata.
+///
+/// Args:
+///     package (str or module): the module or the name of a module or
+///         distribution, e.g. "numpy"
+///     spec (str): the constraint, as in check_version
+///     handle_with (type): type of exception or warning to be raised
+///     message (str): a text to use as the exception/warning message.
+///         Defaults to None, which means naming the package, its version
+///         and the spec.
+///     scheme (str): "pep440" (the default) or "semver"
+///
+/// Returns:
+///     None, if check succeeded.
+///
+/// Raises:
+///     Exception of the type provided by the handle_with parameter,
+///     VersionError by default; importlib.metadata.PackageNotFoundError if
+///     the version cannot be found.
+///
+/// >>> check_package_version("pytest", ">=8")
+/// Traceback (most recent call last):
+///     ...
+/// VersionError: pytest 7.4.0 does not satisfy >=8
+#[pyfunction]
+#[pyo3(signature = (package, spec, /, handle_with=None, message=None, *, scheme="pep440"))]
+pub fn check_package_version(_py: Python,
+                             package: &PyAny,
+                             spec: &str,
+                             handle_with: Option<&PyType>,
+                             message: Option<&str>,
+                             scheme: &str) -> PyResult<()> {
+    let is_disabled = env::var("EASYCHECK_RUN").unwrap_or("1".parse()?) == "0";
+
+    if is_disabled {
+        return Ok(());
+    }
+
+    let (name, module) = match package.downcast::<PyString>() {
+        Ok(name) => { (name.to_str()?.to_string(), _py.import(name).ok()) }
+        Err(_) => { (package.getattr("__name__")?.to_string(), package.downcast::<PyModule>().ok()) }
+    };
+    let version = match module.and_then(|module| module.getattr("__version__").ok()) {
+        Some(version) => { version.str()?.to_string() }
+        None => {
+            _py.import("importlib.metadata")?.call_method1("version", (name.as_str(),))?.extract::<String>()?
+        }
+    };
+
+    if satisfies(&version, spec, scheme)? {
+        return Ok(());
+    }
+    raise_version_error(_py, handle_with, message, format!("{} {} does not satisfy {}", name, version, spec))
+}
+
+#[pyfunction]
+#[allow(unused_variables)]
+#[pyo3(signature = (package, spec, /, handle_with=None, message=None, *, scheme="pep440"))]
+pub fn assert_package_version(_py: Python,
+                              package: &PyAny,
+                              spec: &str,
+                              handle_with: Option<&PyType>,
+                              message: Option<&str>,
+                              scheme: &str) -> PyResult<()> {
+    check_package_version(_py, package, spec, Option::from(PyAssertionError::new_err(()).get_type(_py)), message, scheme)
+}
@@ -1,12 +1,18 @@
+import array
+import datetime as dt
 import decimal
 import fractions
 import math
 import os
 import pytest
+import re
 import warnings
-from collections.abc import Generator
+from abc import ABC, abstractmethod
+from collections.abc import Generator, Iterable, Mapping, Sequence
+from enum import Enum
 from operator import eq, le, lt, gt, ge, ne, is_, is_not
 from pathlib import Path
+from typing import Annotated, Any, Dict, List, Literal, Optional, Protocol, Tuple, Union
 from unittest import mock
 
 from reasycheck.reasycheck import (
@@ -17,6 +23,31 @@ from reasycheck.reasycheck import (
     check_if_in_limits,
     assert_if_in_limits,
     LimitError,
+    check_finite,
+    assert_finite,
+    check_not_nan,
+    assert_not_nan,
+    check_integer_valued,
+    assert_integer_valued,
+    check_positive,
+    assert_positive,
+    check_non_negative,
+    assert_non_negative,
+    check_probability,
+    assert_probability,
+    NotFiniteError,
+    NaNError,
+    NotIntegerValuedError,
+    NotPositiveError,
+    NegativeValueError,
+    NotProbabilityError,
+    check_decimal_places,
+    assert_decimal_places,
+    check_significant_digits,
+    assert_significant_digits,
+    PrecisionError,
+    check_datetime,
+    assert_datetime,
     LengthError,
     check_length,
     check_if_isclose,
@@ -24,8 +55,44 @@ from reasycheck.reasycheck import (
     NotCloseEnoughError,
     check_type,
     assert_type,
+    check_class,
+    assert_class,
+    check_has_attributes,
+    assert_has_attributes,
+    check_callable,
+    assert_callable,
+    SignatureError,
+    check_enum,
+    assert_enum,
+    check_in,
+    assert_in,
+    check_keys,
+    assert_keys,
+    check_subset,
+    assert_subset,
+    check_superset,
+    assert_superset,
+    check_unique,
+    assert_unique,
+    check_sorted,
+    assert_sorted,
+    check_all,
+    assert_all,
+    check_matches,
+    assert_matches,
+    check_format,
+    assert_format,
+    check_version,
+    assert_version,
+    check_package_version,
+    assert_package_version,
+    VersionError,
+    check_any,
+    assert_any,
     check_length,
     assert_length,
+    check_uniform_length,
+    assert_uniform_length,
     check_if_paths_exist,
     assert_paths,
 )
@@ -252,11 +319,251 @@ def test_check_if_in_limits_negative():
         check_if_in_limits(None)
 
 
+def test_check_if_in_limits_interval():
+    assert check_if_in_limits(0, interval="[0, 1)") is None
+    assert check_if_in_limits(0.999, interval="[0, 1)") is None
+    assert check_if_in_limits(1e300, interval="(0, inf]") is None
+    assert check_if_in_limits(-5, interval="(-inf, 0]") is None
+    assert check_if_in_limits(0, 0, 1, include_upper=False) is None
+    assert check_if_in_limits(1, 0, 1, include_lower=False) is None
+    assert check_if_in_limits(1, 0, 1, include_equal=False, include_upper=True) is None
+    with pytest.raises(LimitError, match=r"^x = 1 is not within \[0, 1\)$"):
+        check_if_in_limits(1, interval="[0, 1)")
+    with pytest.raises(LimitError, match=r"^x = 0 is not within \(0, inf\]$"):
+        check_if_in_limits(0, interval=" ( 0 , +inf ] ")
+    with pytest.raises(LimitError, match=r"^x = 0 is not within \(0, 1\]$"):
+        check_if_in_limits(0, 0, 1, include_lower=False)
+    with pytest.raises(LimitError, match=r"^x = 6.5 is not within \[-inf, 5\]$"):
+        check_if_in_limits(6.5, upper_limit=5)
+    with pytest.raises(LimitError, match="^out of range$"):
+        check_if_in_limits(2, interval="[0, 1]", message="out of range")
+    with pytest.raises(ValueError, match="invalid interval '0, 1'"):
+        check_if_in_limits(0, interval="0, 1")
+    with pytest.raises(ValueError, match="invalid interval"):
+        check_if_in_limits(0, interval="[0, nan]")
+    with pytest.raises(ValueError, match="lower bound is greater"):
+        check_if_in_limits(0, interval="[2, 1]")
+    with pytest.raises(ValueError, match="cannot be combined"):
+        check_if_in_limits(0, 0, interval="[0, 1]")
+    with pytest.raises(AssertionError):
+        assert_if_in_limits(1, interval="[0, 1)")
+
+
+def test_check_if_in_limits_quantities():
+    assert check_if_in_limits(500 * 2**20, upper_limit="512MiB") is None
+    assert check_if_in_limits(10**10, "1GB", "1TB") is None
+    assert check_if_in_limits(1.5 * 2**30, upper_limit="2GiB") is None
+    assert check_if_in_limits(29, upper_limit="30s") is None
+    assert check_if_in_limits(5400, "1h 30m", "2h") is None
+    assert check_if_in_limits(5400, interval="[90min, 2 hours)") is None
+    assert check_if_in_limits(0.25, interval="(0, 250ms]") is None
+    with pytest.raises(LimitError, match=r"^x = 600MiB is not within \[-inf, 512MiB\]$"):
+        check_if_in_limits(600 * 2**20, upper_limit="512MiB")
+    with pytest.raises(LimitError, match=r"^x = 11GB is not within \[0B, 10GB\]$"):
+        check_if_in_limits(11 * 10**9, 0, "10GB")
+    with pytest.raises(LimitError, match=r"^x = 2h is not within \[1h, 2h\)$"):
+        check_if_in_limits(7200, interval="[1h, 120m)")
+    with pytest.raises(LimitError, match=r"^x = 90s is not within \[-inf, 1m\]$"):
+        check_if_in_limits(90, upper_limit="1m")
+    with pytest.raises(ValueError, match="cannot compare a size with a duration"):
+        check_if_in_limits(1, "1GB", "1h")
+    with pytest.raises(ValueError, match="cannot compare a duration with a size"):
+        check_if_in_limits(1, "1s", "1MB")
+    with pytest.raises(ValueError, match="invalid quantity '10 parsecs'"):
+        check_if_in_limits(1, upper_limit="10 parsecs")
+    with pytest.raises(ValueError, match="invalid quantity"):
+        check_if_in_limits(1, upper_limit="1h5GB")
+    with pytest.raises(ValueError, match="ambiguous unit 'M' in '512M'"):
+        check_if_in_limits(100, upper_limit="512M")
+    with pytest.raises(ValueError, match="invalid quantity '10Mb'"):
+        check_if_in_limits(1, upper_limit="10Mb")
+    with pytest.raises(ValueError, match="invalid quantity '1gb'"):
+        check_if_in_limits(1, upper_limit="1gb")
+    with pytest.raises(TypeError):
+        check_if_in_limits("5", 0, 10)
+    with pytest.raises(TypeError):
+        check_if_in_limits([1], 0, 1)
+
+
+def test_check_if_in_limits_nan():
+    with pytest.raises(LimitError, match=r"^x is NaN, which is never within \[0, 1\]$"):
+        check_if_in_limits(math.nan, 0, 1)
+    with pytest.raises(LimitError, match="^a limit is NaN"):
+        check_if_in_limits(0.5, math.nan, 1)
+    with pytest.raises(LimitError, match="^custom$"):
+        check_if_in_limits(math.nan, 0, 1, message="custom")
+
+
+def test_check_numeric_scalars():
+    assert check_finite(1.5) is None
+    assert check_not_nan(math.inf) is None
+    assert check_integer_valued(3.0) is None
+    assert check_integer_valued(decimal.Decimal("4")) is None
+    assert check_positive(fractions.Fraction(1, 3)) is None
+    assert check_non_negative(0) is None
+    assert check_probability(1) is None
+    assert check_finite(10**400) is None
+    assert check_not_nan(-(10**400)) is None
+    assert check_integer_valued(10**400) is None
+    assert check_positive(10**400) is None
+    assert check_non_negative(10**400) is None
+    with pytest.raises(NotPositiveError, match=r"got -1000000000"):
+        check_positive(-(10**400))
+    with pytest.raises(NegativeValueError):
+        check_non_negative(-(10**400))
+    with pytest.raises(NotProbabilityError) as error:
+        check_probability(10**400)
+    assert error.value.value == 10**400
+    with pytest.raises(NotFiniteError, match=r"^value must be finite, got inf$") as error:
+        check_finite(math.inf)
+    assert math.isinf(error.value.value)
+    with pytest.raises(NaNError, match=r"^value must be not NaN, got nan$"):
+        check_not_nan(math.nan)
+    with pytest.raises(NotIntegerValuedError, match=r"^value must be integer-valued, got 2.5$"):
+        check_integer_valued(2.5)
+    with pytest.raises(NotPositiveError, match=r"^value must be positive, got 0$"):
+        check_positive(0)
+    with pytest.raises(NegativeValueError, match=r"got Decimal\('-0.1'\)$"):
+        check_non_negative(decimal.Decimal("-0.1"))
+    with pytest.raises(NotProbabilityError, match=r"^value must be between 0 and 1, got 1.2$"):
+        check_probability(1.2)
+    with pytest.raises(LimitError):
+        check_probability(math.nan)
+    with pytest.raises(TypeError):
+        check_positive("1")
+    with pytest.raises(ValueError, match="bad"):
+        check_positive(-1, ValueError, "bad")
+    with pytest.raises(AssertionError):
+        assert_finite(math.nan)
+    with pytest.raises(AssertionError):
+        assert_not_nan(math.nan)
+    with pytest.raises(AssertionError):
+        assert_integer_valued(0.5)
+    with pytest.raises(AssertionError):
+        assert_positive(-1)
+    with pytest.raises(AssertionError):
+        assert_non_negative(-1)
+    with pytest.raises(AssertionError):
+        assert_probability(2)
+
+
+def test_check_decimal_places():
+    assert check_decimal_places(decimal.Decimal("19.99"), 2) is None
+    assert check_decimal_places(decimal.Decimal("19.9900"), 2) is None
+    assert check_decimal_places(decimal.Decimal("-0.00"), 0) is None
+    assert check_decimal_places(decimal.Decimal("1E+2"), 0) is None
+    assert check_decimal_places("0.10", 1) is None
+    assert check_decimal_places(0.1, 1) is None
+    assert check_decimal_places(0.1 + 0.2, 17) is None
+    assert check_decimal_places(12, 0) is None
+    with pytest.raises(PrecisionError, match=r"^Decimal\('19.999'\) has 3 decimal places, at most 2 allowed$") as error:
+        check_decimal_places(decimal.Decimal("19.999"), 2)
+    assert error.value.precision == 3
+    assert issubclass(PrecisionError, LimitError)
+    with pytest.raises(PrecisionError, match="has 17 decimal places") as error:
+        check_decimal_places(0.1 + 0.2, 2)
+    with pytest.raises(PrecisionError, match="has 9 decimal places"):
+        check_decimal_places("1.5e-8", 8)
+    with pytest.raises(PrecisionError, match="has 7 decimal places"):
+        check_decimal_places(1e-7, 2)
+    with pytest.raises(ValueError, match="'abc' is not a finite decimal number"):
+        check_decimal_places("abc", 2)
+    with pytest.raises(ValueError, match="is not a finite decimal number"):
+        check_decimal_places(decimal.Decimal("NaN"), 2)
+    with pytest.raises(TypeError, match="expected Decimal, str, int or float, got 'Fraction'"):
+        check_decimal_places(fractions.Fraction(1, 3), 2)
+    with pytest.raises(TypeError, match="got 'bool'"):
+        check_decimal_places(True, 2)
+    with pytest.raises(ValueError, match="price"):
+        check_decimal_places(1.005, 2, ValueError, "price")
+    with pytest.raises(AssertionError):
+        assert_decimal_places("1.005", 2)
+
+
+def test_check_significant_digits():
+    assert check_significant_digits(decimal.Decimal("0.00120"), 2) is None
+    assert check_significant_digits(1200, 2) is None
+    assert check_significant_digits("6.022e23", 4) is None
+    assert check_significant_digits(0, 0) is None
+    assert check_significant_digits(1 / 3, 16) is None
+    with pytest.raises(PrecisionError, match=r"^0.0012345 has 5 significant digits, at most 3 allowed$") as error:
+        check_significant_digits(0.0012345, 3)
+    assert error.value.precision == 5
+    with pytest.raises(PrecisionError, match="has 4 significant digits"):
+        check_significant_digits("-10.01", 3)
+    with pytest.raises(AssertionError):
+        assert_significant_digits(123, 2)
+
+
+def test_check_datetime():
+    utc = dt.timezone.utc
+    now = dt.datetime.now(utc)
+    start = dt.datetime(2024, 1, 1, tzinfo=utc)
+    end = dt.datetime(2024, 12, 31, tzinfo=utc)
+    assert check_datetime(dt.datetime(2024, 6, 1, tzinfo=utc), start, end, require_tz=True) is None
+    assert check_datetime(start, start, end) is None
+    assert check_datetime(now - dt.timedelta(minutes=5), max_age="1h", allow_future=False) is None
+    assert check_datetime(dt.datetime.now() - dt.timedelta(seconds=10), max_age=60) is None
+    assert check_datetime(now, max_age=dt.timedelta(days=1)) is None
+    with pytest.raises(LimitError, match="^2024-01-01 00:00:00 is not timezone-aware$"):
+        check_datetime(dt.datetime(2024, 1, 1), require_tz=True)
+    with pytest.raises(LimitError, match="^2023-12-31 00:00:00\\+00:00 is before 2024-01-01 00:00:00\\+00:00$"):
+        check_datetime(dt.datetime(2023, 12, 31, tzinfo=utc), start, end)
+    with pytest.raises(LimitError, match="is not after 2024-01-01"):
+        check_datetime(start, start, end, include_equal=False)
+    with pytest.raises(LimitError, match="is not before 2024-12-31"):
+        check_datetime(end, start, end, include_equal=False)
+    with pytest.raises(LimitError, match="is after 2024-12-31"):
+        check_datetime(dt.datetime(2025, 1, 1, tzinfo=utc), not_after=end)
+    with pytest.raises(LimitError, match="is in the future$"):
+        check_datetime(now + dt.timedelta(hours=1), allow_future=False)
+    with pytest.raises(LimitError, match=r"is older than 2:00:00 \(age 3:0"):
+        check_datetime(now - dt.timedelta(hours=3), max_age="2h")
+    with pytest.raises(ValueError, match="max_age must be a duration"):
+        check_datetime(now, max_age="1GB")
+    with pytest.raises(TypeError, match="expected a datetime, got 'date'"):
+        check_datetime(dt.date(2024, 1, 1))
+    with pytest.raises(TypeError):
+        check_datetime(dt.datetime(2024, 6, 1), start)
+    with pytest.raises(ValueError, match="stale"):
+        check_datetime(start, max_age="1d", handle_with=ValueError, message="stale")
+    with pytest.raises(AssertionError):
+        assert_datetime(dt.datetime(2024, 1, 1), require_tz=True)
+
+
+def test_check_numeric_arrays():
+    assert check_finite(array.array("d", [0.0, 1.0])) is None
+    assert check_integer_valued(array.array("q", [1, 2, 3])) is None
+    assert check_positive(array.array("B", [1, 255])) is None
+    assert check_probability(array.array("f", [])) is None
+    with pytest.raises(NotFiniteError, match=r"^values must be finite, got inf at \[1\]$") as error:
+        check_finite(array.array("d", [1.0, math.inf, math.nan]))
+    assert error.value.index == 1
+    with pytest.raises(NaNError, match=r"got nan at \[2\]$"):
+        check_not_nan(array.array("f", [1.0, math.inf, math.nan]))
+    with pytest.raises(NegativeValueError, match=r"^values must be non-negative, got -3.0 at \[0\]$") as error:
+        check_non_negative(array.array("i", [-3, 4]))
+    assert error.value.value == -3
+    with pytest.raises(NotProbabilityError, match=r"got 1.5 at \[1\]$"):
+        check_probability(memoryview(array.array("d", [0.5, 1.5])))
+
+
 def test_check_length_edge_cases():
     with pytest.raises(TypeError, match="required positional argument"):
         check_length("tomato soup is good")
     with pytest.raises(TypeError, match="'int' object is not callable"):
         check_length([1], 1, operator=1)
+    with pytest.raises(TypeError, match="operator requires expected_length"):
+        check_length([1, 2, 3], operator="<", min_length=1)
+    with pytest.raises(ZeroDivisionError):
+        check_length([1], 1, operator=lambda a, b: 1 / 0)
+
+    class NoTruth:
+        def __bool__(self):
+            raise ValueError("no truth value")
+
+    with pytest.raises(ValueError, match="no truth value"):
+        check_length([1], 1, operator=lambda a, b: NoTruth())
 
 
 def test_check_length_positive():
@@ -316,6 +623,108 @@ def test_check_length_negative_warnings():
         assert warn.args[0] == "This is a testing warning"
 
 
+def test_check_length_operator():
+    assert check_length("string", 10, operator=lt) is None
+    assert check_length("string", 6, operator=le) is None
+    assert check_length("string", 3, operator="!=") is None
+    assert check_length("string", 6, operator=">=") is None
+    assert check_length("string", 7, operator="lt") is None
+    with pytest.raises(LengthError):
+        check_length("string", 3, operator=lt)
+    with pytest.raises(LengthError):
+        check_length("string", 6, operator=ne)
+    with pytest.raises(LengthError):
+        check_length("string", 6, operator="<")
+    with pytest.raises(LengthError):
+        check_length("string", 7, operator="gt")
+    with pytest.raises(ValueError, match="unknown comparison operator"):
+        check_length("string", 7, operator="=>")
+
+
+def test_check_length_min_max():
+    assert check_length([1, 2, 3], min_length=1) is None
+    assert check_length([1, 2, 3], max_length=3) is None
+    assert check_length([1, 2, 3], min_length=3, max_length=5) is None
+    assert check_length("abc", 3, min_length=1, max_length=5) is None
+    with pytest.raises(LengthError):
+        check_length([1, 2, 3], min_length=4)
+    with pytest.raises(LengthError):
+        check_length([1, 2, 3], max_length=2)
+    with pytest.raises(LengthError):
+        check_length("abc", 4, min_length=1, max_length=5)
+    with pytest.raises(ValueError):
+        check_length("abc", max_length=2, handle_with=ValueError)
+
+
+def test_check_length_iterators():
+    assert check_length((i for i in range(3)), 3, count_items=True) is None
+    assert check_length(iter([1, 2]), max_length=2, count_items=True) is None
+    assert check_length(iter(range(5)), 5, use_length_hint=True) is None
+    with pytest.raises(LengthError):
+        check_length((i for i in range(3)), 2, count_items=True)
+    with pytest.raises(LengthError):
+        check_length(iter(range(5)), 4, use_length_hint=True)
+    with pytest.raises(TypeError, match="'generator' has no len()"):
+        check_length((i for i in range(3)), 3, use_length_hint=True)
+
+    # counting stops as soon as the check is bound to fail
+    items = iter(range(100))
+    with pytest.raises(LengthError):
+        check_length(items, max_length=3, count_items=True)
+    assert next(items) == 4
+
+    consumed = check_length(
+        (i for i in range(3)), 3, count_items=True, return_items=True
+    )
+    assert consumed == [0, 1, 2]
+    assert check_length([1, 2], 2, return_items=True) == [1, 2]
+
+
+def test_check_length_units():
+    assert check_length("żółw", 4) is None
+    assert check_length("żółw", 7, unit="bytes") is None
+    assert check_length("żółw", 4, unit="chars") is None
+    assert check_length("e\u0301", 2, unit="chars") is None
+    assert check_length("e\u0301", 1, unit="graphemes") is None
+    assert check_length("👍🏽", 1, unit="graphemes") is None
+    assert check_length("żółw".encode(), 7, unit="bytes") is None
+    assert check_length("żółw".encode(), 4, unit="chars") is None
+    assert check_length("żółw", max_length=8, unit="bytes") is None
+    with pytest.raises(LengthError):
+        check_length("żółw", max_length=6, unit="bytes")
+    with pytest.raises(ValueError, match="unit can only be"):
+        check_length("abc", 3, unit="words")
+    with pytest.raises(TypeError, match="only be used with str or bytes"):
+        check_length([1, 2], 2, unit="bytes")
+    with pytest.raises(ValueError, match="not valid UTF-8"):
+        check_length(b"\xff", 1, unit="chars")
+
+
+def test_check_uniform_length():
+    assert check_uniform_length([[1, 2], [3, 4], [5, 6]]) is None
+    assert check_uniform_length([[1, 2], (3, 4), "ab"], 2) is None
+    assert check_uniform_length({"a": [1], "b": [2]}, 1) is None
+    assert check_uniform_length([]) is None
+
+    with pytest.raises(LengthError, match=r"differ from 2: \[2\] has length 1"):
+        check_uniform_length([[1, 2], [3, 4], [5]])
+    with pytest.raises(LengthError) as error:
+        check_uniform_length([[1], [1, 2], [1, 2]])
+    assert error.value.offenders == {0: 1}
+    with pytest.raises(LengthError) as error:
+        check_uniform_length({"a": [1, 2], "b": [1]}, 3)
+    assert error.value.offenders == {"a": 2, "b": 1}
+    assert "expected inner length 3" in str(error.value)
+    with pytest.raises(ValueError, match="ragged"):
+        check_uniform_length(
+            [[1], []], handle_with=ValueError, message="ragged"
+        )
+    with pytest.raises(TypeError, match=r"element \[1\] of type 'int'"):
+        check_uniform_length([[1], 2])
+    with pytest.raises(AssertionError):
+        assert_uniform_length([[1], []])
+
+
 def test_check_if_isclose_edge_cases():
     with pytest.raises(ValueError, match="tolerances must be non-negative"):
         check_if_isclose(1.1, 1.2, abs_tol=-1.0)
@@ -420,6 +829,740 @@ def test_check_type_negative():
         check_type([10, 20], None)
 
 
+def test_check_type_typing_constructs():
+    assert check_type(None, None) is None
+    assert check_type(None, Optional[int]) is None
+    assert check_type(5, Optional[int]) is None
+    assert check_type("a", Union[int, str]) is None
+    assert check_type(5, int | None) is None
+    assert check_type(None, int | None) is None
+    assert check_type("a", Literal["a", "b"]) is None
+    assert check_type(object(), Any) is None
+    assert check_type(5, Annotated[int, "positive"]) is None
+    assert check_type([1], list[int]) is None
+    assert check_type(5, [str, Optional[int]]) is None
+    with pytest.raises(TypeError):
+        check_type(5.0, Optional[int])
+    with pytest.raises(TypeError):
+        check_type(b"a", Union[int, str])
+    with pytest.raises(TypeError):
+        check_type("a", int | None)
+    with pytest.raises(TypeError):
+        check_type("c", Literal["a", "b"])
+    with pytest.raises(TypeError):
+        check_type(True, Literal[1])
+    with pytest.raises(TypeError):
+        check_type("5", Annotated[int, "positive"])
+    with pytest.raises(TypeError):
+        check_type((1,), list[int])
+
+
+def test_check_type_generics():
+    payload = {"a": [1, 2, 3], "b": []}
+    assert check_type(payload, dict[str, list[int]]) is None
+    assert check_type(payload, Dict[str, List[int]]) is None
+    assert check_type(payload, Mapping[str, Sequence[int]]) is None
+    assert check_type({1, 2}, set[int]) is None
+    assert check_type(frozenset("ab"), frozenset[str]) is None
+    assert check_type((1, "a"), tuple[int, str]) is None
+    assert check_type((1, 2, 3), tuple[int, ...]) is None
+    assert check_type((), Tuple[()]) is None
+    assert check_type([1, None], list[Optional[int]]) is None
+    assert check_type((i for i in "ab"), Iterable[int]) is None
+    assert check_type([1, 2, "x"], list[int], sample=2) is None
+
+    with pytest.raises(TypeError, match=r"payload\['a'\]\[3\]: expected int, got str"):
+        check_type({"a": [1, 2, 3, "4"]}, dict[str, list[int]], name="payload")
+    with pytest.raises(TypeError, match=r"item\[1\]: expected key of type str, got int"):
+        check_type({1: []}, dict[str, list[int]])
+    with pytest.raises(TypeError, match="expected tuple of length 2, got length 3"):
+        check_type((1, "a", 2), tuple[int, str])
+    with pytest.raises(TypeError, match=r"item\[2\]: expected int, got float"):
+        check_type((1, 2, 3.0), tuple[int, ...])
+    with pytest.raises(TypeError, match=r"item\[0\]\[1\]: expected int, got str"):
+        check_type([[1, "a"]], Optional[list[list[int]]])
+    with pytest.raises(TypeError, match=r"item: expected list\[int\], got tuple"):
+        check_type((1,), list[int])
+    with pytest.raises(TypeError, match="custom message"):
+        check_type([1, "x"], list[int], message="custom message")
+    with pytest.raises(TypeError, match="expected int"):
+        check_type([1, 2, "x"], list[int], sample=3)
+
+
+def test_check_type_strict():
+    class MyInt(int):
+        pass
+
+    assert check_type(5, int, strict=True) is None
+    assert check_type(True, bool, strict=True) is None
+    assert check_type(True, int) is None
+    assert check_type(5, [float, int], strict=True) is None
+    assert check_type([1, 2], list[int], strict=True) is None
+    assert check_type([1, 2], Sequence[int], strict=True) is None
+    assert check_type(MyInt(5), int) is None
+    with pytest.raises(TypeError, match="expected int, got bool"):
+        check_type(True, int, strict=True)
+    with pytest.raises(TypeError):
+        check_type(True, [float, int], strict=True)
+    with pytest.raises(TypeError):
+        check_type(False, {float, int}, strict=True)
+    with pytest.raises(TypeError):
+        check_type(MyInt(5), int, strict=True)
+    with pytest.raises(TypeError, match=r"item\[1\]: expected int, got bool"):
+        check_type([1, True], list[int], strict=True)
+    with pytest.raises(TypeError):
+        check_type([1, True], Sequence[int], strict=True)
+
+
+def test_check_type_coerce():
+    assert check_type("5", int, coerce=True) == 5
+    assert check_type(" 5 ", int, coerce=True) == 5
+    assert check_type(5.0, int, coerce=True) == 5
+    assert check_type("1.5", float, coerce=True) == 1.5
+    assert check_type(2, float, coerce=True) == 2.0
+    assert check_type("true", bool, coerce=True) is True
+    assert check_type("No", bool, coerce=True) is False
+    assert check_type(0, bool, coerce=True) is False
+    assert check_type("a/b", Path, coerce=True) == Path("a/b")
+    assert check_type(Path("a"), str, coerce=True) == "a"
+    assert check_type(1.1, decimal.Decimal, coerce=True) == decimal.Decimal("1.1")
+    assert check_type(7, int, coerce=True) == 7
+    assert check_type("7", Optional[int], coerce=True) == 7
+    assert check_type("x", [int, str], coerce=True) == "x"
+    assert check_type("2", Literal[1, 2], coerce=True) == 2
+    assert check_type(["1", "2"], list[int], coerce=True) == [1, 2]
+    assert check_type(("1", 2.0), tuple[float, int], coerce=True) == (1.0, 2)
+    assert check_type({"a": "1"}, dict[str, int], coerce=True) == {"a": 1}
+    assert check_type(["1", "1"], set[int], coerce=True) == {1}
+    assert check_type(["1"], Sequence[int], coerce=True) == [1]
+    assert check_type(7, int) is None
+
+    with pytest.raises(TypeError, match="expected int, got str"):
+        check_type("5", int)
+    with pytest.raises(TypeError, match="expected int, got str"):
+        check_type("5.5", int, coerce=True)
+    with pytest.raises(TypeError):
+        check_type(5.5, int, coerce=True)
+    with pytest.raises(TypeError):
+        check_type(True, int, strict=True, coerce=True)
+    with pytest.raises(TypeError):
+        check_type("maybe", bool, coerce=True)
+    with pytest.raises(TypeError):
+        check_type("3", Literal[1, 2], coerce=True)
+    with pytest.raises(TypeError):
+        check_type("12", list[int], coerce=True)
+    with pytest.raises(ValueError, match="not a number"):
+        check_type(
+            "abc", float, handle_with=ValueError, message="not a number",
+            coerce=True,
+        )
+    with mock.patch.dict(os.environ, {"EASYCHECK_RUN": "0"}):
+        assert check_type("5", int, coerce=True) == 5
+        assert check_type("abc", int, coerce=True) == "abc"
+
+
+class Plugin(ABC):
+    @abstractmethod
+    def load(self): ...
+
+    @abstractmethod
+    def unload(self): ...
+
+
+class Reader(Protocol):
+    name: str
+
+    def read(self, size): ...
+
+    def close(self): ...
+
+
+def test_check_class():
+    class FullPlugin(Plugin):
+        name = "full"
+
+        def load(self): ...
+
+        def unload(self): ...
+
+        def read(self, size=-1): ...
+
+        def close(self, *args): ...
+
+    class HalfPlugin(Plugin):
+        def load(self): ...
+
+    class FileLike:
+        def __init__(self):
+            self.name = "file"
+
+        def read(self): ...
+
+    assert check_class(FullPlugin, Plugin, concrete=True) is None
+    assert check_class(FullPlugin, [int, Plugin]) is None
+    assert check_class(FullPlugin, protocol=Reader) is None
+    with pytest.raises(TypeError, match="FileLike is not a subclass of Plugin"):
+        check_class(FileLike, Plugin)
+    with pytest.raises(
+        TypeError, match="HalfPlugin does not implement abstract methods: unload"
+    ) as error:
+        check_class(HalfPlugin, Plugin, concrete=True)
+    assert error.value.missing == ["unload"]
+    with pytest.raises(TypeError) as error:
+        check_class(FileLike, protocol=Reader)
+    assert error.value.missing == ["close", "name"]
+    assert "missing close, name" in str(error.value)
+    assert "read accepts 0 positional argument(s) instead of 1" in str(error.value)
+
+    class StaticReader:
+        name = "static"
+
+        @staticmethod
+        def read(size): ...
+
+        @classmethod
+        def close(cls): ...
+
+    class BadStaticReader(StaticReader):
+        @staticmethod
+        def read(): ...
+
+    assert check_class(StaticReader, protocol=Reader) is None
+    with pytest.raises(
+        TypeError, match=r"read accepts 0 positional argument\(s\) instead of 1"
+    ):
+        check_class(BadStaticReader, protocol=Reader)
+    with pytest.raises(TypeError, match="expected a class"):
+        check_class(FullPlugin(), Plugin)
+    with pytest.raises(ImportError, match="bad plugin"):
+        check_class(HalfPlugin, concrete=True, handle_with=ImportError, message="bad plugin")
+    with pytest.raises(AssertionError):
+        assert_class(FileLike, Plugin)
+
+
+def test_check_has_attributes():
+    class File:
+        name = "data.csv"
+        size = 10
+        closed = False
+
+        def read(self): ...
+
+    file = File()
+    assert check_has_attributes(file, ["name", "read"]) is None
+    assert check_has_attributes(file, "read") is None
+    with pytest.raises(AttributeError, match="missing attributes: write$"):
+        check_has_attributes(file, "write")
+    assert (
+        check_has_attributes(file, {"read": callable, "name": str, "size": int})
+        is None
+    )
+    assert check_has_attributes(file, {"size": Optional[int], "closed": None}) is None
+    assert check_has_attributes(file, {"closed": int}) is None
+    with pytest.raises(AttributeError, match="closed: expected int, got bool"):
+        check_has_attributes(file, {"closed": int}, strict=True)
+    with pytest.raises(AttributeError) as error:
+        check_has_attributes(
+            file, {"write": callable, "name": callable, "size": str, "mode": str}
+        )
+    assert error.value.missing == ["write", "mode"]
+    assert error.value.mistyped == ["name", "size"]
+    assert str(error.value) == (
+        "missing attributes: write, mode; name: expected callable, got str; "
+        "size: expected str, got int"
+    )
+    with pytest.raises(TypeError, match="not file-like"):
+        check_has_attributes(
+            1, ["read"], handle_with=TypeError, message="not file-like"
+        )
+    with pytest.raises(AssertionError):
+        assert_has_attributes(1, ["read"])
+
+
+def test_check_callable():
+    def callback(event, context=None, *, retries=3): ...
+
+    def handler(*args, **kwargs): ...
+
+    assert check_callable(callback) is None
+    assert check_callable(callback, 1) is None
+    assert check_callable(callback, 2, ["retries"]) is None
+    assert check_callable(callback, keywords=["event", "retries"]) is None
+    assert check_callable(callback, 1, "retries") is None
+    assert check_callable(handler, 5, ["anything"]) is None
+    assert check_callable(print, 3) is None
+    with pytest.raises(SignatureError, match="'int' object is not callable"):
+        check_callable(1)
+    with pytest.raises(
+        SignatureError,
+        match=(
+            "callback cannot be called with 3 positional argument.s.: "
+            "too many positional arguments"
+        ),
+    ):
+        check_callable(callback, 3)
+    with pytest.raises(SignatureError, match="missing a required argument: 'event'"):
+        check_callable(callback, 0)
+    with pytest.raises(SignatureError, match="keyword argument.s. timeout"):
+        check_callable(callback, 1, ["timeout"])
+    with pytest.raises(SignatureError, match="keyword argument.s. timeout:"):
+        check_callable(callback, 1, "timeout")
+    with pytest.raises(TypeError, match="bad callback"):
+        check_callable(
+            callback, 3, handle_with=TypeError, message="bad callback"
+        )
+    with pytest.raises(AssertionError):
+        assert_callable(callback, 3)
+
+
+class Color(Enum):
+    RED = "red"
+    GREEN = "green"
+
+
+def test_check_enum():
+    assert check_enum("red", Color) is Color.RED
+    assert check_enum(Color.GREEN, Color) is Color.GREEN
+    assert check_enum("GREEN", Color, by="name") is Color.GREEN
+    assert check_enum(Color.RED, Color, by="name") is Color.RED
+    with pytest.raises(
+        ValueError,
+        match="'blue' is not a valid Color value; valid values: 'red', 'green'",
+    ):
+        check_enum("blue", Color)
+    with pytest.raises(
+        ValueError, match="'red' is not a valid Color name; valid names: 'RED', 'GREEN'"
+    ):
+        check_enum("red", Color, by="name")
+    with pytest.raises(ValueError):
+        check_enum(["red"], Color)
+    with pytest.raises(KeyError, match="unknown color"):
+        check_enum("blue", Color, handle_with=KeyError, message="unknown color")
+    with pytest.raises(ValueError, match="by can only be"):
+        check_enum("blue", Color, by="label")
+    with pytest.raises(TypeError, match="subclass of enum.Enum"):
+        check_enum("red", dict)
+    with pytest.raises(AssertionError):
+        assert_enum("blue", Color)
+    with mock.patch.dict(os.environ, {"EASYCHECK_RUN": "0"}):
+        assert check_enum("red", Color) is Color.RED
+        assert check_enum("blue", Color) == "blue"
+
+
+def test_check_in():
+    environments = ["development", "staging", "production"]
+    assert check_in("staging", environments) is None
+    assert check_in(2, {1, 2, 3}) is None
+    assert check_in("a", iter(["a", "b"])) is None
+    with pytest.raises(
+        ValueError,
+        match=(
+            r"'prodution' is not one of \['development', 'staging', 'production'\]; "
+            "did you mean 'production'\\?"
+        ),
+    ) as error:
+        check_in("prodution", environments)
+    assert error.value.suggestion == "production"
+    with pytest.raises(ValueError, match="did you mean 'staging'"):
+        check_in("Stagin", environments)
+    with pytest.raises(ValueError) as error:
+        check_in("qa", environments)
+    assert error.value.suggestion is None
+    assert "did you mean" not in str(error.value)
+    with pytest.raises(ValueError, match=r"4 is not one of \[1, 2, 3\]$"):
+        check_in(4, [1, 2, 3])
+    with pytest.raises(KeyError, match="unknown environment"):
+        check_in("qa", environments, KeyError, "unknown environment")
+    with pytest.raises(AssertionError):
+        assert_in("qa", environments)
+
+
+def test_check_keys():
+    config = {"host": "localhost", "port": 8080, "debug": True}
+    assert check_keys(config, required=["host", "port"]) is None
+    assert check_keys(config, ["host"], ["port", "debug"], allow_extra=False) is None
+    with pytest.raises(ValueError, match=r"^missing keys: 'user', 'password'$") as error:
+        check_keys(config, required=["host", "user", "password"])
+    assert error.value.missing == ["user", "password"]
+    assert error.value.unexpected == []
+    with pytest.raises(
+        ValueError,
+        match=r"^missing keys: 'port'; unexpected keys: 'prt' \(did you mean 'port'\?\), 'debug'$",
+    ) as error:
+        check_keys({"host": "x", "prt": 1, "debug": 1}, required=["host", "port"], allow_extra=False)
+    assert error.value.missing == ["port"]
+    assert error.value.unexpected == ["prt", "debug"]
+    with pytest.raises(KeyError, match="bad config"):
+        check_keys(config, required=["user"], handle_with=KeyError, message="bad config")
+    with pytest.raises(AssertionError):
+        assert_keys(config, required=["user"])
+
+
+def test_check_subset_superset():
+    assert check_subset(["a", "b", "a"], {"a", "b", "c"}) is None
+    assert check_superset(iter([1, 2, 3]), [1, 3]) is None
+    with pytest.raises(ValueError, match=r"^unexpected elements: 'x', 'y'$") as error:
+        check_subset(["a", "x", "y", "x"], ["a", "b"])
+    assert error.value.unexpected == ["x", "y"]
+    assert error.value.missing == []
+    with pytest.raises(ValueError, match=r"^missing elements: 4$") as error:
+        check_superset({1, 2, 3}, [1, 4, 4])
+    assert error.value.missing == [4]
+    assert error.value.unexpected == []
+    with pytest.raises(TypeError):
+        check_subset([[1]], [1])
+    with pytest.raises(AssertionError):
+        assert_subset([1], [2])
+    with pytest.raises(AssertionError):
+        assert_superset([1], [2])
+
+
+def test_check_unique():
+    assert check_unique([1, 2, 3]) is None
+    assert check_unique(iter("abc")) is None
+    assert check_unique([]) is None
+    with pytest.raises(ValueError, match=r"^duplicate values: 3 at \[0, 2\], 1 at \[1, 4\]$") as error:
+        check_unique([3, 1, 3, 2, 1])
+    assert error.value.duplicates == {3: [0, 2], 1: [1, 4]}
+    users = [{"id": "a1"}, {"id": "b2"}, {"id": "a1"}]
+    with pytest.raises(ValueError, match="'a1' at \\[0, 2\\]") as error:
+        check_unique(users, key=lambda user: user["id"])
+    assert error.value.duplicates == {"a1": [0, 2]}
+    with pytest.raises(TypeError):
+        check_unique([[1], [1]])
+    with pytest.raises(KeyError, match="duplicated ids"):
+        check_unique([1, 1], handle_with=KeyError, message="duplicated ids")
+    with pytest.raises(AssertionError):
+        assert_unique([1, 1])
+
+
+def test_check_sorted():
+    assert check_sorted([1, 2, 2, 5]) is None
+    assert check_sorted((0.5, 1.5, 2.5), strict=True) is None
+    assert check_sorted(array.array("d", [3.0, 2.0, 1.0]), reverse=True, strict=True) is None
+    assert check_sorted(iter(["a", "b", "c"])) is None
+    assert check_sorted([]) is None
+    with pytest.raises(ValueError, match=r"^items are not strictly increasing: \[1\] 2 is followed by \[2\] 2$") as error:
+        check_sorted([1, 2, 2, 5, 4], strict=True)
+    assert error.value.index == 2
+    with pytest.raises(ValueError, match=r"^items are not non-decreasing: \[3\] 5 is followed by \[4\] 4$"):
+        check_sorted([1, 2, 2, 5, 4])
+    with pytest.raises(ValueError, match=r"\[0\] 1.0 is followed by \[1\] nan"):
+        check_sorted([1.0, math.nan])
+    with pytest.raises(ValueError, match=r"not non-increasing: \[1\] 2 is followed by \[2\] 3"):
+        check_sorted(array.array("i", [4, 2, 3]), reverse=True)
+    with pytest.raises(ValueError, match=r"\[1\] 18446744073709551617 is followed by \[2\] 18446744073709551616"):
+        check_sorted([1, 2**64 + 1, 2**64])
+    with pytest.raises(ValueError, match=r"\[1\] 2\.5 is followed by \[2\] 2"):
+        check_sorted([1, 2.5, 2])
+    events = [{"at": 1}, {"at": 3}, {"at": 2}]
+    with pytest.raises(ValueError, match=r"\[1\] \{'at': 3\} is followed by \[2\] \{'at': 2\}") as error:
+        check_sorted(events, key=lambda event: event["at"], strict=True)
+    assert error.value.index == 2
+    with pytest.raises(TypeError):
+        check_sorted([1, "a"])
+    with pytest.raises(AssertionError):
+        assert_sorted([2, 1])
+
+
+def is_positive(x):
+    return x > 0
+
+
+def test_check_all():
+    assert check_all([1, 2, 3], is_positive) is None
+    assert check_all([], is_positive) is None
+    assert check_all([0, 50, 120], "check_if_in_limits", 0, 120) is None
+    assert check_all(["ab", "cd"], "check_length", expected_length=2) is None
+    with pytest.raises(AssertionError, match=r"^is_positive fails for 2 of 4 items: \[1\] -1, \[3\] -2$") as error:
+        check_all([3, -1, 5, -2], is_positive)
+    assert error.value.indices == [1, 3]
+    with pytest.raises(AssertionError, match=r"^check_length fails for 1 of 2 items: \[1\] 'abc' \(LengthError") as error:
+        check_all(["ab", "abc"], "check_length", 2)
+    assert error.value.indices == [1]
+    with pytest.raises(AssertionError, match=r"\[0\] -1, \[1\] -2, and 3 more$") as error:
+        check_all(range(-1, -6, -1), is_positive, max_failures=2)
+    assert error.value.indices == [0, 1, 2, 3, 4]
+    with pytest.raises(AssertionError, match=r"\(SignatureError: 'int' object is not callable\)"):
+        check_all([1], "check_callable")
+    with pytest.raises(ValueError, match="unknown check 'check_lenght'; did you mean 'check_length'\\?"):
+        check_all([1], "check_lenght")
+    with pytest.raises(ValueError, match="did you mean 'check_unique'\\?"):
+        check_all([1], "check_uniqe")
+    with pytest.raises(TypeError, match=r"^check_length\(\) got an unexpected keyword argument 'lenght'$"):
+        check_all(iter(["ab", "cd"]), "check_length", lenght=2)
+    with pytest.raises(TypeError, match=r"^check_if_in_limits\(\) got an unexpected keyword argument 'bogus'$"):
+        check_all([1, 2], "check_if_in_limits", 0, 5, bogus=1)
+    with pytest.raises(TypeError, match="too many positional arguments"):
+        check_all([], "check_finite", 1, 2, 3, 4)
+    with pytest.raises(ZeroDivisionError):
+        check_all([0], lambda x: 1 / x)
+    with pytest.raises(ValueError, match="negative values"):
+        check_all([-1], is_positive, handle_with=ValueError, message="negative values")
+    with pytest.raises(AssertionError):
+        assert_all([-1], is_positive)
+
+
+def test_check_any():
+    assert check_any([-1, 2], is_positive) is None
+    assert check_any(iter([1, 0]), lambda x: 1 / x) is None
+    assert check_any([1, "a"], "check_type", str) is None
+    with pytest.raises(AssertionError, match=r"^is_positive fails for all 2 items: \[0\] -1, \[1\] -2$") as error:
+        check_any([-1, -2], is_positive)
+    assert error.value.indices == [0, 1]
+    with pytest.raises(AssertionError, match="cannot hold for an empty iterable"):
+        check_any([], is_positive)
+    with pytest.raises(AssertionError):
+        assert_any([0], is_positive)
+
+
+def test_check_matches():
+    assert check_matches("AB-12", r"[A-Z]{2}-\d+") is None
+    assert check_matches("id: AB-12", r"[A-Z]{2}-\d+", full=False) is None
+    assert check_matches(b"\x00abc", rb"\x00[a-z]+") is None
+    assert check_matches(b"\xff", rb"\xff") is None
+    assert check_matches(b"\xff", rb".") is None
+    assert check_matches(b"\xe9", b"\xe9") is None
+    assert check_matches(b"caf\xc3\xa9", b"caf\xc3\xa9") is None
+    assert check_matches(b"\x90\xa0", b"[\x80-\xff]+") is None
+    assert check_matches(b"\xe9", b"\\\xe9") is None
+    with pytest.raises(ValueError, match="does not fully match"):
+        check_matches(b"\xe9\xe9", b"\xe9")
+    with pytest.raises(ValueError, match="does not fully match"):
+        check_matches(b"\xc3\xa9", rb"\w")
+    assert check_matches(iter(["a1", "b2"]), "[a-z][0-9]") is None
+    assert check_matches([], "x") is None
+    with pytest.raises(ValueError, match=r"^'id: AB-12' does not fully match '\[A-Z\]\{2\}-\\\\d\+'$"):
+        check_matches("id: AB-12", r"[A-Z]{2}-\d+")
+    with pytest.raises(ValueError, match=r"^'abc' does not match '\\\\d'$"):
+        check_matches("abc", r"\d", full=False)
+    with pytest.raises(ValueError, match=r"^2 of 4 items do not fully match '\[a-z\]\[0-9\]': \[1\] 'B2', \[3\] 'c'$") as error:
+        check_matches(["a1", "B2", "c3", "c"], "[a-z][0-9]")
+    assert error.value.indices == [1, 3]
+    with pytest.raises(ValueError, match=r"\[9\] '9', and 2 more$"):
+        check_matches([str(i) for i in range(12)], "[a-z]")
+    with pytest.raises(ValueError, match="a|b"):
+        check_matches("ab", "a|b")
+    with pytest.raises(ValueError, match="invalid pattern"):
+        check_matches("ab", "(a")
+    with pytest.raises(ValueError, match="invalid pattern"):
+        check_matches("aa", r"(a)\1")
+    with pytest.raises(UnicodeEncodeError, match="surrogates not allowed"):
+        check_matches("\ud800", ".")
+    with pytest.raises(UnicodeEncodeError):
+        check_matches(["a", "\ud800"], ".")
+    with pytest.raises(TypeError, match="cannot match a str pattern against 'bytes'"):
+        check_matches(b"a", "a")
+    with pytest.raises(TypeError, match="item \\[1\\]: expected str, got 'int'"):
+        check_matches(["a", 1], "a")
+    with pytest.raises(TypeError, match="pattern must be str or bytes"):
+        check_matches("a", 1)
+    with pytest.raises(KeyError, match="bad id"):
+        check_matches("x", "y", KeyError, "bad id")
+    with pytest.raises(AssertionError):
+        assert_matches("x", "y")
+
+
+def test_check_format():
+    valid = [
+        ("jane.doe+tag@mail.example.com", "email"),
+        ("https://example.com", "url"),
+        ("http://user:pw@localhost:8080/path?q=1#top", "url"),
+        ("ftp://192.168.0.1/file", "url"),
+        ("http://[::1]:80/", "url"),
+        ("12345678-1234-5678-1234-56781234ABCD", "uuid"),
+        ("192.168.0.255", "ipv4"),
+        ("2001:db8::ff00:42:8329", "ipv6"),
+        ("::ffff:192.0.2.128", "ipv6"),
+        ("10.0.0.0/8", "cidr"),
+        ("2001:db8::/32", "cidr"),
+        ("2024-02-29", "iso_date"),
+        ("2024-02-29T13:45", "iso_datetime"),
+        ("2024-02-29T13:45:00.123+01:00", "iso_datetime"),
+        ("2024-02-29 13:45:00Z", "iso_datetime"),
+        ("2024-02-29T13:45:00-0530", "iso_datetime"),
+        ("1.4.0", "semver"),
+        ("1.0.0-rc.1+build.5", "semver"),
+        ("my-host.example.org", "hostname"),
+        ("localhost.", "hostname"),
+    ]
+    for value, format in valid:
+        assert check_format(value, format) is None
+
+
+def test_check_format_negative():
+    invalid = [
+        ("jane.doe@", "email"),
+        ("jane..doe@example.com", "email"),
+        ("jane@localhost", "email"),
+        ("example.com", "url"),
+        ("https://exa mple.com", "url"),
+        ("http://example.com:99999", "url"),
+        ("http://[::g]/", "url"),
+        ("12345678-1234-5678-1234-56781234567", "uuid"),
+        ("1234567812345678123456781234567g", "uuid"),
+        ("256.1.1.1", "ipv4"),
+        ("01.1.1.1", "ipv4"),
+        ("2001:db8:::1", "ipv6"),
+        ("10.0.0.0/33", "cidr"),
+        ("10.0.0.0", "cidr"),
+        ("2023-02-29", "iso_date"),
+        ("2024-13-01", "iso_date"),
+        ("24-01-01", "iso_date"),
+        ("2024-02-29T24:00", "iso_datetime"),
+        ("2024-02-29T13:45+1:00", "iso_datetime"),
+        ("2024-02-29T13:45:00.", "iso_datetime"),
+        ("2024-02-29T13:45+\u00e9\u00e9\u00e9", "iso_datetime"),
+        ("2024-02-29", "iso_datetime"),
+        ("1.4", "semver"),
+        ("01.4.0", "semver"),
+        ("-host.example.org", "hostname"),
+        ("a" * 64 + ".com", "hostname"),
+        ("under_score.com", "hostname"),
+    ]
+    for value, format in invalid:
+        with pytest.raises(ValueError, match=f"is not a valid {format}$"):
+            check_format(value, format)
+
+
+def test_check_format_arguments():
+    with pytest.raises(ValueError, match="unknown format 'emal'; did you mean 'email'\\?"):
+        check_format("a@b.c", "emal")
+    with pytest.raises(TypeError, match="expected str, got 'bytes'"):
+        check_format(b"1.2.3", "semver")
+    with pytest.raises(KeyError, match="bad address"):
+        check_format("x", "email", KeyError, "bad address")
+    with pytest.raises(AssertionError):
+        assert_format("x", "uuid")
+    with mock.patch.dict(os.environ, {"EASYCHECK_RUN": "0"}):
+        assert check_format("x", "uuid") is None
+
+
+def test_check_version_pep440():
+    satisfied = [
+        ("1.4.2", ">=1.4,<2"),
+        ("1.10", ">1.9"),
+        ("1.0.0", "==1.0"),
+        ("1.0+cpu", "==1.0"),
+        ("1.0+cpu", "==1.0+cpu"),
+        ("2.2.5", "~=2.2"),
+        ("1.4.9", "~=1.4.2"),
+        ("1.7.3", "==1.*"),
+        ("1.0rc1", "==1.0.*"),
+        ("2.0rc1", ">=1.4"),
+        ("1.0.post1", ">1.0.post0"),
+        ("1.0.dev3", "<1.0a1"),
+        ("1!0.5", ">=2"),
+        ("v1.0-Beta.2", "==1.0b2"),
+        ("1.0", "===1.0"),
+        ("0.1", ""),
+    ]
+    for version, spec in satisfied:
+        assert check_version(version, spec) is None
+    not_satisfied = [
+        ("1.3.9", ">=1.4,<2"),
+        ("2.0rc1", ">=1.4,<2"),
+        ("2.0.dev0", "<2"),
+        ("1.0.post1", ">1.0"),
+        ("1.0+cpu", ">1.0"),
+        ("2.3", "~=2.2.0"),
+        ("1.0", "!=1.0.0"),
+        ("2.0", "==1.*"),
+        ("1.0.0", "===1.0"),
+    ]
+    for version, spec in not_satisfied:
+        with pytest.raises(VersionError, match=f"^version {re.escape(version)} does not satisfy {re.escape(spec)}$"):
+            check_version(version, spec)
+
+
+def test_check_version_semver():
+    satisfied = [
+        ("1.4.2", "^1.4"),
+        ("1.9.0", "^1.4.2"),
+        ("0.2.9", "^0.2.3"),
+        ("0.0.3", "^0.0.3"),
+        ("1.4.9", "~1.4.2"),
+        ("1.4.0", ">=1.4.0 <2"),
+        ("1.4.0", ">= 1.4.0, < 2"),
+        ("1.6.1", "1.4 - 1.6"),
+        ("2.1.0", "^1 || ^2"),
+        ("1.2.9", "1.2.x"),
+        ("3.0.0", "*"),
+        ("1.0.0-rc.2", ">1.0.0-rc.1"),
+        ("1.0.0-alpha.beta", ">1.0.0-alpha.1"),
+        ("v1.2.3+build.7", "=1.2.3"),
+        ("1.3.0", ">1.2"),
+    ]
+    for version, spec in satisfied:
+        assert check_version(version, spec, scheme="semver") is None
+    not_satisfied = [
+        ("2.0.0", "^1.4"),
+        ("2.0.0-rc.1", "^1.4"),
+        ("0.3.0", "^0.2.3"),
+        ("0.0.4", "^0.0.3"),
+        ("1.5.0", "~1.4.2"),
+        ("1.7.0", "1.4 - 1.6"),
+        ("3.0.0", "^1 || ^2"),
+        ("1.0.0-rc.1", "1.0.0"),
+        ("1.2.9", ">1.2"),
+        ("1.2.0-alpha", "<1.2"),
+    ]
+    for version, spec in not_satisfied:
+        with pytest.raises(VersionError, match="does not satisfy"):
+            check_version(version, spec, scheme="semver")
+
+
+def test_check_version_arguments():
+    with pytest.raises(ValueError, match="invalid PEP 440 version 'one'"):
+        check_version("one", ">=1")
+    with pytest.raises(ValueError, match="invalid PEP 440 specifier '=>1'"):
+        check_version("1.0", "=>1")
+    with pytest.raises(ValueError, match="invalid PEP 440 specifier '~=1'"):
+        check_version("1.0", "~=1")
+    with pytest.raises(ValueError, match="invalid PEP 440 specifier '>=1.0\\+local'"):
+        check_version("1.0", ">=1.0+local")
+    with pytest.raises(ValueError, match="invalid semantic version '1.0'"):
+        check_version("1.0", "^1", scheme="semver")
+    with pytest.raises(ValueError, match="invalid semver range '\\^1.0.0.0'"):
+        check_version("1.0.0", "^1.0.0.0", scheme="semver")
+    max_part = 2**64 - 1
+    assert check_version(f"{max_part}.1.0", f"^{max_part}", scheme="semver") is None
+    assert check_version(f"1.{max_part}.5", f"<=1.{max_part}", scheme="semver") is None
+    assert check_version(f"0.{max_part}.9", f"~0.{max_part}", scheme="semver") is None
+    assert check_version(f"1.2.{max_part}", f"=1.2.{max_part}", scheme="semver") is None
+    assert check_version(f"{max_part}.0.0", f"{max_part}", scheme="semver") is None
+    with pytest.raises(VersionError):
+        check_version("1.0.0", f"^{max_part}", scheme="semver")
+    with pytest.raises(VersionError):
+        check_version("2.0.0", f"<=1.{max_part}", scheme="semver")
+    with pytest.raises(VersionError):
+        check_version(f"{max_part}.0.0", f">{max_part}", scheme="semver")
+    with pytest.raises(ValueError, match="scheme can only be"):
+        check_version("1.0", ">=1", scheme="calver")
+    with pytest.raises(RuntimeError, match="too old"):
+        check_version("1.0", ">=2", RuntimeError, "too old")
+    with pytest.raises(AssertionError):
+        assert_version("1.0", ">=2")
+
+
+def test_check_package_version():
+    fake = mock.Mock(spec=["__name__", "__version__"], __version__="1.4.2")
+    fake.__name__ = "fake"
+    with mock.patch.dict("sys.modules", {"fake": fake}):
+        assert check_package_version("fake", ">=1.4,<2") is None
+        with pytest.raises(VersionError, match="^fake 1.4.2 does not satisfy >=2$"):
+            check_package_version("fake", ">=2")
+    with mock.patch("importlib.metadata.version", return_value="23.1"):
+        assert check_package_version("some_distribution", ">=23") is None
+        with pytest.raises(VersionError, match="^some_distribution 23.1 does not satisfy <23$"):
+            check_package_version("some_distribution", "<23")
+    with pytest.raises(ModuleNotFoundError):
+        check_package_version("surely_not_installed_package", ">=1")
+    with pytest.raises(AssertionError):
+        with mock.patch("importlib.metadata.version", return_value="1.0"):
+            assert_package_version("some_distribution", ">=2")
+
+
 def test_check_type_negative_warnings():
     with warnings.catch_warnings(record=True) as w:
         check_type(
@@ -983,6 +2126,19 @@ def test_check_if_paths_exist_negative_warnings():
     assert "Path issue" in str(check_result[0])
     assert check_result[1] == [non_existing_path]
 
+
+def test_check_if_paths_exist_suggestion():
+    with pytest.raises(FileNotFoundError, match=r"did you mean Cargo.toml\?"):
+        check_if_paths_exist("Cargo.tom")
+    with pytest.raises(FileNotFoundError, match=r"did you mean src/lib.rs\?"):
+        check_if_paths_exist(Path("src/lbi.rs"))
+    with pytest.raises(FileNotFoundError) as error:
+        check_if_paths_exist("src/completely_different.rs")
+    assert "did you mean" not in str(error.value)
+    check_result = check_if_paths_exist("Cargo.tom", execution_mode="return")
+    assert str(check_result[0]) == ""
+    assert check_result[1] == ["Cargo.tom"]
+
 #
 # def test_raise_edge_cases():
 #     with pytest.raises(TypeError, match="required positional argument"):